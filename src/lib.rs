@@ -52,6 +52,29 @@
 //! assert_eq!(enum_type.variants[2].0.name, "Triangle");
 //! ```
 //!
+//! ## Converting back to tokens
+//!
+//! All syntax types implement [`quote::ToTokens`], so they can be interpolated in `quote!` invocations after being inspected or modified.
+//! This also provides a `to_token_stream()` method which reconstructs the tokens, including the original delimiters and spans:
+//!
+//! ```rust
+//! use venial::{parse_item, Item};
+//! use quote::{quote, ToTokens};
+//!
+//! let Ok(Item::Struct(mut struct_type)) = parse_item(quote!(
+//!     struct Hello(i32);
+//! )) else { unreachable!() };
+//! struct_type.name = quote::format_ident!("World");
+//!
+//! assert_eq!(struct_type.to_token_stream().to_string(), "struct World (i32) ;");
+//! ```
+//!
+//! ## Interoperability with syn
+//!
+//! With the `syn` feature enabled, [`Item`], [`Struct`], [`Enum`], [`Union`] and [`Function`] implement
+//! `TryFrom` conversions to and from their syn counterparts (e.g. `syn::ItemStruct`). This allows migrating
+//! a codebase incrementally. Conversions go through token streams, so they cost as much as parsing.
+//!
 //! ## Spans
 //!
 //! Spans mark the beginning and end of tokens in the source code and can be used to generate precise error messages, highlighting certain parts.
@@ -110,14 +133,20 @@ mod parse;
 mod parse_extern;
 mod parse_fn;
 mod parse_impl;
+mod parse_meta;
 mod parse_mod;
 mod parse_type;
 mod parse_utils;
 mod punctuated;
+#[cfg(feature = "syn")]
+mod syn_compat;
 mod types;
 mod types_edition;
 
 pub use error::Error;
-pub use parse::{consume_item, parse_item};
+pub use parse::{
+    consume_item, consume_item_lenient, parse_attribute_macro, parse_item, parse_item_lenient,
+    parse_items, parse_path, parse_type,
+};
 pub use punctuated::Punctuated;
 pub use types::*;
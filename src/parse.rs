@@ -3,13 +3,20 @@ use crate::parse_fn::consume_macro;
 use crate::parse_impl::{
     consume_either_fn_type_const_static_impl, parse_const_or_static, parse_impl, parse_trait,
 };
+use crate::parse_meta::parse_meta_list;
 use crate::parse_mod::{parse_mod, parse_use_declaration};
 use crate::parse_type::{
-    consume_generic_params, consume_item_name, consume_where_clause, parse_enum_variants,
-    parse_named_fields, parse_tuple_fields,
+    consume_field_type, consume_generic_params, consume_item_name, consume_where_clause,
+    parse_enum_variants, parse_named_fields, parse_tuple_fields,
+};
+use crate::parse_utils::{
+    consume_outer_attributes, consume_path, consume_punct, consume_vis_marker,
+};
+use crate::types::{
+    Attribute, Constant, Enum, ExternBlock, ExternCrate, Fields, Function, GroupSpan, Impl, Item,
+    Macro, Meta, Module, Path, Punctuated, Struct, Trait, TypeAlias, TypeExpr, Union, UnknownItem,
+    UseDeclaration, VisMarker,
 };
-use crate::parse_utils::{consume_outer_attributes, consume_punct, consume_vis_marker};
-use crate::types::{Enum, Fields, GroupSpan, Item, Struct, Union};
 use proc_macro2::token_stream::IntoIter;
 use proc_macro2::{Delimiter, TokenStream, TokenTree};
 use std::iter::Peekable;
@@ -44,21 +51,11 @@ use std::iter::Peekable;
 ///
 /// ## Errors
 ///
-/// Venial doesn't support enum discriminants with multiple non-grouped tokens. E.g.:
-///
-/// ```rust
-/// # #[cfg(FALSE)]
-/// enum MyEnum {
-///     A = 42,           // Ok
-///     B = "hello",      // Ok
-///     C = CONSTANT,     // Ok
-///     D = FOO + BAR,    // MACRO ERROR
-///     E = (FOO + BAR),  // Ok
-/// }
-/// ```
+/// Returns an error for some malformed inputs that Rust would reject, such as a
+/// union without named fields.
 pub fn parse_item(tokens: TokenStream) -> Result<Item, Error> {
     let mut tokens = tokens.into_iter().peekable();
-    let declaration = consume_item(&mut tokens);
+    let declaration = consume_item(&mut tokens)?;
 
     if tokens.peek().is_some() {
         panic!(
@@ -67,7 +64,107 @@ pub fn parse_item(tokens: TokenStream) -> Result<Item, Error> {
         );
     }
 
-    declaration
+    Ok(declaration)
+}
+
+/// Same as [parse_item].
+impl TryFrom<TokenStream> for Item {
+    type Error = Error;
+
+    fn try_from(tokens: TokenStream) -> Result<Self, Self::Error> {
+        parse_item(tokens)
+    }
+}
+
+macro_rules! implement_try_from_tokens {
+    ($Kind:ident, $name:literal) => {
+        /// Same as [parse_item], but returns an error if the item is of a different kind.
+        impl TryFrom<TokenStream> for $Kind {
+            type Error = Error;
+
+            fn try_from(tokens: TokenStream) -> Result<Self, Self::Error> {
+                match parse_item(tokens)? {
+                    Item::$Kind(item) => Ok(item),
+                    item => Err(Error::new_at_tokens(
+                        &item,
+                        format!(
+                            "cannot parse {}: expected {}, found {}",
+                            $name,
+                            $name,
+                            item_kind_name(&item)
+                        ),
+                    )),
+                }
+            }
+        }
+    };
+}
+
+/// Describes the kind of an item, for error messages.
+fn item_kind_name(item: &Item) -> &'static str {
+    match item {
+        Item::Struct(_) => "struct",
+        Item::Enum(_) => "enum",
+        Item::Union(_) => "union",
+        Item::Module(_) => "mod",
+        Item::Trait(_) => "trait",
+        Item::Impl(_) => "impl block",
+        Item::TypeAlias(_) => "type alias",
+        Item::Function(_) => "function",
+        Item::Constant(_) => "const or static",
+        Item::UseDeclaration(_) => "use declaration",
+        Item::Macro(_) => "macro",
+        Item::ExternBlock(_) => "extern block",
+        Item::ExternCrate(_) => "extern crate",
+        Item::Unknown(_) => "unknown item",
+    }
+}
+
+implement_try_from_tokens!(Struct, "struct");
+implement_try_from_tokens!(Enum, "enum");
+implement_try_from_tokens!(Union, "union");
+implement_try_from_tokens!(Module, "mod");
+implement_try_from_tokens!(Trait, "trait");
+implement_try_from_tokens!(Impl, "impl block");
+implement_try_from_tokens!(TypeAlias, "type alias");
+implement_try_from_tokens!(Function, "function");
+implement_try_from_tokens!(Constant, "const or static");
+implement_try_from_tokens!(UseDeclaration, "use declaration");
+implement_try_from_tokens!(Macro, "macro");
+implement_try_from_tokens!(ExternBlock, "extern block");
+implement_try_from_tokens!(ExternCrate, "extern crate");
+
+/// Parses a sequence of items, such as the content of a file or of a `mod` block.
+///
+/// ## Example
+///
+/// ```
+/// # use venial::{parse_items, Item};
+/// # use quote::quote;
+/// let items = parse_items(quote!(
+///     use std::fmt;
+///     struct Foo;
+///     impl fmt::Debug for Foo {}
+/// )).unwrap();
+/// assert_eq!(items.len(), 3);
+/// assert!(matches!(items[2], Item::Impl(_)));
+/// ```
+///
+/// ## Panics
+///
+/// Panics if one of the items doesn't parse as a valid Rust declaration, like [parse_item].
+///
+/// ## Errors
+///
+/// Returns the error of the first item that fails to parse; see [parse_item].
+pub fn parse_items(tokens: TokenStream) -> Result<Vec<Item>, Error> {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut items = vec![];
+    while tokens.peek().is_some() {
+        items.push(consume_item(&mut tokens)?);
+    }
+
+    Ok(items)
 }
 
 /// Consume an item declaration from a token stream.
@@ -82,8 +179,70 @@ pub fn parse_item(tokens: TokenStream) -> Result<Item, Error> {
 ///
 /// ## Errors
 ///
-/// Venial doesn't support enum discriminants with multiple non-grouped tokens.
+/// Same as [parse_item].
 pub fn consume_item(tokens: &mut Peekable<IntoIter>) -> Result<Item, Error> {
+    consume_item_with_mode(tokens, false)
+}
+
+/// Parses an item declaration, returning [`Item::Unknown`] instead of panicking if the item kind is not supported.
+///
+/// This is useful to walk over a sequence of items, skipping or re-emitting the ones that are not understood.
+/// An unknown item extends up to the next `;` or `{ }` block. Unsupported members nested in modules,
+/// impl blocks, traits and extern blocks are returned as unknown as well.
+///
+/// ```
+/// # use venial::{parse_item_lenient, Item};
+/// # use quote::quote;
+/// let item = parse_item_lenient(quote!(
+///     #[attr]
+///     pub unsupported Foo { bar }
+/// )).unwrap();
+/// let Item::Unknown(unknown) = &item else { panic!() };
+/// assert_eq!(unknown.attributes.len(), 1);
+/// assert_eq!(unknown.tokens.len(), 3);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if there are leftover tokens, or for malformed items of a supported kind.
+///
+/// ## Errors
+///
+/// Same as [parse_item].
+pub fn parse_item_lenient(tokens: TokenStream) -> Result<Item, Error> {
+    let mut tokens = tokens.into_iter().peekable();
+    let declaration = consume_item_lenient(&mut tokens)?;
+
+    if tokens.peek().is_some() {
+        panic!(
+            "unexpected trailing tokens after declaration: {}",
+            tokens.collect::<TokenStream>()
+        );
+    }
+
+    Ok(declaration)
+}
+
+/// Consume an item declaration from a token stream, returning [`Item::Unknown`] for unsupported item kinds.
+///
+/// This is the same as [parse_item_lenient], except it doesn't panic if there are
+/// leftover tokens.
+///
+/// ## Panics
+///
+/// Panics for malformed items of a supported kind.
+///
+/// ## Errors
+///
+/// Same as [parse_item].
+pub fn consume_item_lenient(tokens: &mut Peekable<IntoIter>) -> Result<Item, Error> {
+    consume_item_with_mode(tokens, true)
+}
+
+pub(crate) fn consume_item_with_mode(
+    tokens: &mut Peekable<IntoIter>,
+    lenient: bool,
+) -> Result<Item, Error> {
     let attributes = consume_outer_attributes(tokens);
     let vis_marker = consume_vis_marker(tokens);
 
@@ -92,33 +251,44 @@ pub fn consume_item(tokens: &mut Peekable<IntoIter>) -> Result<Item, Error> {
             // struct keyword
             tokens.next().unwrap();
 
-            let struct_name = consume_item_name(tokens);
-            let generic_params = consume_generic_params(tokens);
-            let mut where_clause = consume_where_clause(tokens);
+            let struct_name = consume_item_name(tokens)?;
+            let generic_params = consume_generic_params(tokens)?;
+            let mut where_clause = consume_where_clause(tokens)?;
 
-            let struct_fields = match tokens
-                .peek()
-                .expect("cannot parse struct: missing body or semicolon")
-            {
-                TokenTree::Punct(punct) if punct.as_char() == ';' => Fields::Unit,
-                TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+            let struct_fields = match tokens.peek() {
+                Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => Fields::Unit,
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
                     let group = group.clone();
                     // Consume group
                     tokens.next();
-                    Fields::Tuple(parse_tuple_fields(group))
+                    Fields::Tuple(parse_tuple_fields(group)?)
                 }
-                TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
                     let group = group.clone();
                     // Consume group
                     tokens.next();
-                    Fields::Named(parse_named_fields(group))
+                    Fields::Named(parse_named_fields(group)?)
+                }
+                Some(token) => {
+                    return Err(Error::new_at_span(
+                        token.span(),
+                        format!(
+                            "cannot parse struct: expected `;`, `( )` or `{{ }}`, found `{}`",
+                            token
+                        ),
+                    ));
+                }
+                None => {
+                    return Err(Error::new_at_span(
+                        struct_name.span(),
+                        "cannot parse struct: expected `;`, `( )` or `{ }`, found end of stream",
+                    ));
                 }
-                token => panic!("cannot parse struct: unexpected token {:?}", token),
             };
 
             if matches!(struct_fields, Fields::Tuple(_)) {
                 assert!(where_clause.is_none());
-                where_clause = consume_where_clause(tokens);
+                where_clause = consume_where_clause(tokens)?;
             }
 
             let semicolon = consume_punct(tokens, ';');
@@ -138,15 +308,29 @@ pub fn consume_item(tokens: &mut Peekable<IntoIter>) -> Result<Item, Error> {
             // enum keyword
             tokens.next().unwrap();
 
-            let enum_name = consume_item_name(tokens);
-            let generic_params = consume_generic_params(tokens);
-            let where_clause = consume_where_clause(tokens);
+            let enum_name = consume_item_name(tokens)?;
+            let generic_params = consume_generic_params(tokens)?;
+            let where_clause = consume_where_clause(tokens)?;
 
-            let (group, enum_variants) = match tokens.next().unwrap() {
-                TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+            let (group, enum_variants) = match tokens.next() {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
                     (group.clone(), parse_enum_variants(group.stream()))
                 }
-                token => panic!("cannot parse enum: unexpected token {:?}", token),
+                Some(token) => {
+                    return Err(Error::new_at_span(
+                        token.span(),
+                        format!(
+                            "cannot parse enum: expected variants in `{{ }}`, found `{}`",
+                            token
+                        ),
+                    ));
+                }
+                None => {
+                    return Err(Error::new_at_span(
+                        enum_name.span(),
+                        "cannot parse enum: expected variants in `{ }`, found end of stream",
+                    ));
+                }
             };
 
             Item::Enum(Enum {
@@ -164,15 +348,29 @@ pub fn consume_item(tokens: &mut Peekable<IntoIter>) -> Result<Item, Error> {
             // union keyword
             tokens.next().unwrap();
 
-            let union_name = consume_item_name(tokens);
-            let generic_params = consume_generic_params(tokens);
-            let where_clause = consume_where_clause(tokens);
+            let union_name = consume_item_name(tokens)?;
+            let generic_params = consume_generic_params(tokens)?;
+            let where_clause = consume_where_clause(tokens)?;
 
-            let union_fields = match tokens.next().unwrap() {
-                TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
-                    parse_named_fields(group)
+            let union_fields = match tokens.next() {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+                    parse_named_fields(group)?
+                }
+                Some(token) => {
+                    return Err(Error::new_at_span(
+                        token.span(),
+                        format!(
+                            "cannot parse union: expected named fields in `{{ }}`, found `{}`",
+                            token
+                        ),
+                    ));
+                }
+                None => {
+                    return Err(Error::new_at_span(
+                        union_name.span(),
+                        "cannot parse union: expected named fields in `{ }`, found end of stream",
+                    ));
                 }
-                token => panic!("cannot parse union: unexpected token {:?}", token),
             };
 
             Item::Union(Union {
@@ -186,23 +384,25 @@ pub fn consume_item(tokens: &mut Peekable<IntoIter>) -> Result<Item, Error> {
             })
         }
         Some(TokenTree::Ident(keyword)) if keyword == "mod" => {
-            let mod_decl = parse_mod(tokens, attributes, vis_marker);
+            let mod_decl = parse_mod(tokens, attributes, vis_marker, lenient)?;
             Item::Module(mod_decl)
         }
-        Some(TokenTree::Ident(keyword)) if keyword == "trait" => {
-            let trait_decl = parse_trait(tokens, attributes, vis_marker);
+        Some(TokenTree::Ident(keyword))
+            if keyword == "trait" || (keyword == "auto" && is_auto_trait(tokens)) =>
+        {
+            let trait_decl = parse_trait(tokens, attributes, vis_marker, lenient)?;
             Item::Trait(trait_decl)
         }
         Some(TokenTree::Ident(keyword)) if keyword == "impl" => {
-            let impl_decl = parse_impl(tokens, attributes);
+            let impl_decl = parse_impl(tokens, attributes, lenient)?;
             Item::Impl(impl_decl)
         }
         Some(TokenTree::Ident(keyword)) if keyword == "static" => {
-            let static_decl = parse_const_or_static(tokens, attributes, vis_marker);
+            let static_decl = parse_const_or_static(tokens, attributes, vis_marker)?;
             Item::Constant(static_decl)
         }
         Some(TokenTree::Ident(keyword)) if keyword == "use" => {
-            let use_decl = parse_use_declaration(tokens, attributes, vis_marker);
+            let use_decl = parse_use_declaration(tokens, attributes, vis_marker)?;
 
             Item::UseDeclaration(use_decl)
         }
@@ -219,11 +419,14 @@ pub fn consume_item(tokens: &mut Peekable<IntoIter>) -> Result<Item, Error> {
                 attributes,
                 vis_marker,
                 "fn/type/const/static/extern/extern crate",
-            )
+                lenient,
+            )?
         }
         Some(token) => {
-            if let Some(macro_) = consume_macro(tokens, attributes) {
+            if let Some(macro_) = consume_macro(tokens, attributes.clone())? {
                 Item::Macro(macro_)
+            } else if lenient {
+                Item::Unknown(consume_unknown_item(tokens, attributes, vis_marker))
             } else {
                 panic!(
                     "cannot parse declaration: expected keyword struct/enum/union/type/trait/impl/mod/default/const/async/unsafe/extern/fn/static or macro, found token {:?}",
@@ -237,3 +440,138 @@ pub fn consume_item(tokens: &mut Peekable<IntoIter>) -> Result<Item, Error> {
     };
     Ok(declaration)
 }
+
+/// Returns true if the next tokens are `auto trait`, as opposed to e.g. a macro named `auto`.
+fn is_auto_trait(tokens: &Peekable<IntoIter>) -> bool {
+    let mut lookahead = tokens.clone();
+    lookahead.next();
+    matches!(lookahead.next(), Some(TokenTree::Ident(ident)) if ident == "trait")
+}
+
+pub(crate) fn consume_unknown_item(
+    tokens: &mut Peekable<IntoIter>,
+    attributes: Vec<Attribute>,
+    vis_marker: Option<VisMarker>,
+) -> UnknownItem {
+    let mut item_tokens = vec![];
+    for token in tokens.by_ref() {
+        let is_end = match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ';' => true,
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => true,
+            _ => false,
+        };
+        item_tokens.push(token);
+        if is_end {
+            break;
+        }
+    }
+
+    UnknownItem {
+        attributes,
+        vis_marker,
+        tokens: item_tokens,
+    }
+}
+
+/// Parses the token stream of a standalone type, such as `Vec<Foo>` or `&'a mut [u8]`.
+///
+/// This is useful for attributes taking a type as argument, e.g. `#[wrap(Foo<Bar>)]`.
+///
+/// ## Example
+///
+/// ```
+/// # use venial::parse_type;
+/// # use quote::quote;
+/// let ty = parse_type(quote!(&'a mut Vec<u8>)).unwrap();
+/// let reference = ty.as_reference().unwrap();
+/// assert!(reference.is_mut());
+/// ```
+///
+/// ## Errors
+///
+/// Returns an error if the token stream is empty, or if there are leftover tokens after the type,
+/// such as a top-level `,`.
+pub fn parse_type(tokens: TokenStream) -> Result<TypeExpr, Error> {
+    let mut tokens = tokens.into_iter().peekable();
+    let ty_tokens = consume_field_type(&mut tokens)?;
+
+    match tokens.peek() {
+        None => Ok(TypeExpr { tokens: ty_tokens }),
+        Some(token) => Err(Error::new_at_span(
+            token.span(),
+            format!("cannot parse type: unexpected trailing token `{}`", token),
+        )),
+    }
+}
+
+/// Parses the token stream of a standalone path, such as `crate::helpers::foo` or `::std::vec::Vec<T>`.
+///
+/// This is useful for attributes taking a path as argument, e.g. `#[via(crate::helpers::foo)]`.
+///
+/// ## Example
+///
+/// ```
+/// # use venial::parse_path;
+/// # use quote::quote;
+/// let path = parse_path(quote!(crate::helpers::foo::<u8>)).unwrap();
+/// assert_eq!(path.segments.len(), 3);
+/// assert!(path.segments[2].generic_args.is_some());
+/// ```
+///
+/// ## Errors
+///
+/// Returns an error if the token stream is not exactly one path.
+pub fn parse_path(tokens: TokenStream) -> Result<Path, Error> {
+    let span = match tokens.clone().into_iter().next() {
+        Some(token) => token.span(),
+        None => {
+            return Err(Error::new(
+                "cannot parse path: expected tokens, found end of stream",
+            ))
+        }
+    };
+
+    match consume_path(tokens.clone().into_iter().peekable()) {
+        Some(path) => Ok(path),
+        None => Err(Error::new_at_span(
+            span,
+            format!("cannot parse path: expected path, found `{}`", tokens),
+        )),
+    }
+}
+
+/// Parses the two token streams received by an attribute macro: the attribute arguments and the annotated item.
+///
+/// The arguments are parsed as a comma-separated list of [`Meta`] items, e.g. `skip, rename = "x", with(path)`.
+///
+/// ## Example
+///
+/// ```
+/// # use venial::{parse_attribute_macro, Meta};
+/// # use quote::quote;
+/// // #[my_macro(skip, rename = "Bar")]
+/// // struct Foo;
+/// let (args, item) = parse_attribute_macro(
+///     quote!(skip, rename = "Bar"),
+///     quote!(struct Foo;),
+/// ).unwrap();
+///
+/// assert_eq!(args.len(), 2);
+/// assert!(matches!(args[0].0, Meta::Path(_)));
+/// assert!(matches!(args[1].0, Meta::NameValue { .. }));
+/// assert_eq!(item.name().unwrap().to_string(), "Foo");
+/// ```
+///
+/// ## Errors
+///
+/// Returns an error if the arguments are not a comma-separated list of paths, `name = value` pairs,
+/// `name(...)` lists and literals, or if the item fails to parse (see [`parse_item`]).
+pub fn parse_attribute_macro(
+    attr: TokenStream,
+    item: TokenStream,
+) -> Result<(Punctuated<Meta>, Item), Error> {
+    let args = parse_meta_list(attr)?;
+    let item = parse_item(item)?;
+
+    Ok((args, item))
+}
@@ -1,5 +1,6 @@
+use crate::error::Error;
 use crate::parse_impl::parse_impl_body;
-use crate::parse_utils::{consume_ident, parse_any_ident, parse_ident, parse_punct, TokenIter};
+use crate::parse_utils::{consume_ident, parse_any_ident, parse_punct, TokenIter};
 use crate::{Attribute, ExternBlock, ExternCrate, VisMarker};
 use proc_macro2::{Delimiter, TokenTree};
 
@@ -22,19 +23,17 @@ fn consume_extern_crate(
     let name = parse_any_ident(tokens, "extern crate");
     let tk_as = consume_ident(tokens, "as");
 
-    let alias;
-    let tk_underscore;
-    if tk_as.is_some() {
-        alias = Some(parse_any_ident(tokens, "extern crate: alias"));
-        if alias.is_none() {
-            tk_underscore = Some(parse_ident(tokens, "_", "extern crate"));
+    let (alias, tk_underscore) = if tk_as.is_some() {
+        // `_` is lexed as an identifier, so tell it apart from a regular alias here
+        let ident = parse_any_ident(tokens, "extern crate: alias");
+        if ident == "_" {
+            (None, Some(ident))
         } else {
-            tk_underscore = None;
+            (Some(ident), None)
         }
     } else {
-        alias = None;
-        tk_underscore = None;
-    }
+        (None, None)
+    };
 
     let tk_semicolon = parse_punct(tokens, ';', "extern crate");
 
@@ -55,17 +54,23 @@ pub(crate) fn parse_extern_block(
     tokens: &mut TokenIter,
     attributes: Vec<Attribute>,
     vis_marker: Option<VisMarker>,
-) -> ExternBlock {
-    consume_extern_block(tokens, attributes, vis_marker).expect("cannot parse extern block")
+    lenient: bool,
+) -> Result<ExternBlock, Error> {
+    let extern_block = consume_extern_block(tokens, attributes, vis_marker, lenient)?;
+    Ok(extern_block.expect("cannot parse extern block"))
 }
 
 fn consume_extern_block(
     tokens: &mut TokenIter,
     attributes: Vec<Attribute>,
     vis_marker: Option<VisMarker>,
-) -> Option<ExternBlock> {
+    lenient: bool,
+) -> Result<Option<ExternBlock>, Error> {
     let tk_unsafe = consume_ident(tokens, "unsafe");
-    let tk_extern = consume_ident(tokens, "extern")?;
+    let tk_extern = match consume_ident(tokens, "extern") {
+        Some(tk_extern) => tk_extern,
+        None => return Ok(None),
+    };
 
     let extern_abi = match tokens.peek() {
         Some(TokenTree::Literal(lit)) => {
@@ -78,15 +83,26 @@ fn consume_extern_block(
 
     let (tk_braces, inner_attributes, body_items) = match tokens.next() {
         Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
-            parse_impl_body(group, true)
+            parse_impl_body(group, true, lenient)?
         }
-        _ => {
-            // Only here we know that it's not an extern crate or extern block, so try other options on call-site (fn).
-            return None;
+        Some(token) => {
+            return Err(Error::new_at_span(
+                token.span(),
+                format!(
+                    "cannot parse extern block: expected body in `{{ }}`, found `{}`",
+                    token
+                ),
+            ));
+        }
+        None => {
+            return Err(Error::new_at_span(
+                tk_extern.span(),
+                "cannot parse extern block: expected body in `{ }`, found end of stream",
+            ));
         }
     };
 
-    Some(ExternBlock {
+    Ok(Some(ExternBlock {
         attributes,
         vis_marker,
         tk_unsafe,
@@ -95,5 +111,5 @@ fn consume_extern_block(
         tk_braces,
         inner_attributes,
         body_items,
-    })
+    }))
 }
@@ -1,3 +1,4 @@
+use crate::error::Error;
 use crate::parse_type::{
     consume_field_type, consume_generic_params, consume_item_name, consume_lifetime,
     consume_where_clause,
@@ -69,7 +70,7 @@ pub(crate) fn consume_fn_qualifiers(tokens: &mut TokenIter) -> FnQualifiers {
     }
 }
 
-fn parse_fn_params(tokens: TokenStream) -> Punctuated<FnParam> {
+fn parse_fn_params(tokens: TokenStream) -> Result<Punctuated<FnParam>, Error> {
     let mut fields = Punctuated::new();
 
     let mut tokens = tokens.into_iter().peekable();
@@ -80,31 +81,29 @@ fn parse_fn_params(tokens: TokenStream) -> Punctuated<FnParam> {
         let attributes = consume_outer_attributes(&mut tokens);
 
         let tk_ref = consume_punct(&mut tokens, '&');
-        let lifetime = consume_lifetime(&mut tokens, false);
+        let lifetime = consume_lifetime(&mut tokens);
         let tk_mut = consume_ident(&mut tokens, "mut");
         let tk_self = consume_ident(&mut tokens, "self");
 
-        let param = if let Some(tk_self) = tk_self {
-            FnParam::Receiver(FnReceiverParam {
+        let param = match (tk_self, tokens.peek()) {
+            // Receiver with explicit type, e.g. `self: Box<Self>`
+            (Some(tk_self), Some(TokenTree::Punct(punct)))
+                if tk_ref.is_none() && punct.as_char() == ':' =>
+            {
+                parse_fn_typed_param(&mut tokens, attributes, tk_mut, tk_self)?
+            }
+            (Some(tk_self), _) => FnParam::Receiver(FnReceiverParam {
                 attributes,
                 tk_ref,
                 lifetime,
                 tk_mut,
                 tk_self,
-            })
-        } else {
-            // TODO - handle non-ident argument names
-            let param_name = parse_any_ident(&mut tokens, "fn param name");
-            let tk_colon = parse_punct(&mut tokens, ':', "fn params");
-
-            let ty_tokens = consume_field_type(&mut tokens);
-            FnParam::Typed(FnTypedParam {
-                attributes,
-                tk_mut,
-                name: param_name,
-                tk_colon,
-                ty: TypeExpr { tokens: ty_tokens },
-            })
+            }),
+            (None, _) => {
+                // TODO - handle non-ident argument names
+                let param_name = parse_any_ident(&mut tokens, "fn param name");
+                parse_fn_typed_param(&mut tokens, attributes, tk_mut, param_name)?
+            }
         };
 
         let comma = consume_comma(&mut tokens);
@@ -112,7 +111,25 @@ fn parse_fn_params(tokens: TokenStream) -> Punctuated<FnParam> {
         fields.push(param, comma);
     }
 
-    fields
+    Ok(fields)
+}
+
+fn parse_fn_typed_param(
+    tokens: &mut TokenIter,
+    attributes: Vec<Attribute>,
+    tk_mut: Option<Ident>,
+    name: Ident,
+) -> Result<FnParam, Error> {
+    let tk_colon = parse_punct(tokens, ':', "fn params");
+    let ty_tokens = consume_field_type(tokens)?;
+
+    Ok(FnParam::Typed(FnTypedParam {
+        attributes,
+        tk_mut,
+        name,
+        tk_colon,
+        ty: TypeExpr { tokens: ty_tokens },
+    }))
 }
 
 fn consume_fn_return(tokens: &mut TokenIter) -> Option<([Punct; 2], TypeExpr)> {
@@ -143,13 +160,15 @@ fn consume_fn_return(tokens: &mut TokenIter) -> Option<([Punct; 2], TypeExpr)> {
 /// Tries to parse a function definition.
 ///
 /// Panics when the following tokens do not constitute a function definition, with one exception:
-/// when `const` is followed by an identifier which is not `fn`, then `None` is returned. This is to
-/// allow fallback to a constant declaration (both can begin with the `const` token).
+/// when the qualifiers are followed by another item keyword (e.g. `const NAME` or `unsafe impl`),
+/// then `Ok(Err(NotFunction))` is returned, to allow fallback to that other item.
+///
+/// Returns an error if the function itself is malformed, e.g. has no valid name.
 pub(crate) fn consume_fn(
     tokens: &mut TokenIter,
     attributes: Vec<Attribute>,
     vis_marker: Option<VisMarker>,
-) -> Result<Function, NotFunction> {
+) -> Result<Result<Function, NotFunction>, Error> {
     // TODO consider multiple-lookahead instead of potentially cloning many tokens
     let before_start = tokens.clone();
     let qualifiers = consume_fn_qualifiers(tokens);
@@ -162,25 +181,27 @@ pub(crate) fn consume_fn(
                 ident.clone()
             } else if qualifiers.tk_extern.is_some() && ident == "crate" {
                 *tokens = before_start; // rollback
-                return Err(NotFunction::ExternCrate);
+                return Ok(Err(NotFunction::ExternCrate));
             } else if ident == "static" {
                 // rollback iterator, could be start of const declaration
                 *tokens = before_start;
-                return Err(NotFunction::Static);
+                return Ok(Err(NotFunction::Static));
             } else if qualifiers.has_only_const_xor_unsafe() {
                 // This is not a function, detect what else it is.
                 // Note: detection already done here, because then we only need the lookahead/rollback once.
                 let declaration_type = if qualifiers.tk_const.is_some() {
                     NotFunction::Const
                 } else if qualifiers.tk_unsafe.is_some() {
-                    if ident == "trait" {
+                    let is_auto_trait = ident == "auto"
+                        && matches!(tokens.peek(), Some(TokenTree::Ident(next)) if next == "trait");
+                    if ident == "trait" || is_auto_trait {
                         NotFunction::Trait
                     } else if ident == "impl" {
                         NotFunction::Impl
                     } else if ident == "mod" {
                         NotFunction::Mod
                     } else {
-                        panic!("expected one of 'fn|trait|impl|mod' after 'unsafe', got {ident:?}")
+                        panic!("expected one of 'fn|trait|auto|impl|mod' after 'unsafe', got {ident:?}")
                     }
                 } else {
                     unreachable!()
@@ -188,7 +209,7 @@ pub(crate) fn consume_fn(
 
                 // rollback iterator, could be start of const declaration
                 *tokens = before_start;
-                return Err(declaration_type);
+                return Ok(Err(declaration_type));
             } else {
                 panic!("expected 'fn' keyword, got ident '{}'", ident)
             }
@@ -197,15 +218,14 @@ pub(crate) fn consume_fn(
         // extern "C" { ...
         Some(TokenTree::Literal(_)) if qualifiers.tk_extern.is_some() => {
             *tokens = before_start; // rollback
-            return Err(NotFunction::ExternBlock);
+            return Ok(Err(NotFunction::ExternBlock));
         }
 
         // extern { ...
-        Some(TokenTree::Group(group))
-            if qualifiers.tk_extern.is_some() && group.delimiter() == Delimiter::Brace =>
-        {
+        // Other groups or end of stream are reported as malformed extern blocks.
+        Some(TokenTree::Group(_)) | None if qualifiers.tk_extern.is_some() => {
             *tokens = before_start; // rollback
-            return Err(NotFunction::ExternBlock);
+            return Ok(Err(NotFunction::ExternBlock));
         }
 
         _ => {
@@ -213,14 +233,28 @@ pub(crate) fn consume_fn(
         }
     };
 
-    let fn_name = consume_item_name(tokens);
-    let generic_params = consume_generic_params(tokens);
+    let fn_name = consume_item_name(tokens)?;
+    let generic_params = consume_generic_params(tokens)?;
 
-    let (params, tk_params_parens) = match tokens.next().unwrap() {
-        TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
-            (parse_fn_params(group.stream()), GroupSpan::new(&group))
+    let (params, tk_params_parens) = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            (parse_fn_params(group.stream())?, GroupSpan::new(&group))
+        }
+        Some(token) => {
+            return Err(Error::new_at_span(
+                token.span(),
+                format!(
+                    "cannot parse function: expected parameters in `( )`, found `{}`",
+                    token
+                ),
+            ));
+        }
+        None => {
+            return Err(Error::new_at_span(
+                fn_name.span(),
+                "cannot parse function: expected parameters in `( )`, found end of stream",
+            ));
         }
-        _ => panic!("cannot parse function; missing parameter list"),
     };
 
     let (tk_return_arrow, return_ty) = if let Some((arrow, ty)) = consume_fn_return(tokens) {
@@ -229,17 +263,31 @@ pub(crate) fn consume_fn(
         (None, None)
     };
 
-    let where_clause = consume_where_clause(tokens);
+    let where_clause = consume_where_clause(tokens)?;
 
-    let (function_body, tk_semicolon) = match &tokens.next().unwrap() {
-        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
-            (Some(group.clone()), None)
+    let (function_body, tk_semicolon) = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+            (Some(group), None)
+        }
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => (None, Some(punct)),
+        Some(token) => {
+            return Err(Error::new_at_span(
+                token.span(),
+                format!(
+                    "cannot parse function: expected `{{ }}` or `;`, found `{}`",
+                    token
+                ),
+            ));
+        }
+        None => {
+            return Err(Error::new_at_span(
+                fn_name.span(),
+                "cannot parse function: expected `{ }` or `;`, found end of stream",
+            ));
         }
-        TokenTree::Punct(punct) if punct.as_char() == ';' => (None, Some(punct.clone())),
-        _ => panic!("cannot parse function; missing body or `;`"),
     };
 
-    Ok(Function {
+    Ok(Ok(Function {
         attributes,
         vis_marker,
         qualifiers,
@@ -253,43 +301,88 @@ pub(crate) fn consume_fn(
         return_ty,
         tk_semicolon,
         body: function_body,
-    })
+    }))
 }
 
-pub(crate) fn consume_macro(tokens: &mut TokenIter, attributes: Vec<Attribute>) -> Option<Macro> {
+/// Tries to parse a macro invocation or `macro_rules!` declaration.
+///
+/// Returns `Ok(None)` if the tokens don't start with `name!`, and an error if the macro has no valid body.
+pub(crate) fn consume_macro(
+    tokens: &mut TokenIter,
+    attributes: Vec<Attribute>,
+) -> Result<Option<Macro>, Error> {
     // TODO consider multiple-lookahead instead of potentially cloning many tokens
     let before_start = tokens.clone();
 
-    match consume_macro_inner(tokens, attributes) {
-        Some(macro_) => Some(macro_),
+    match consume_macro_inner(tokens, attributes)? {
+        Some(macro_) => Ok(Some(macro_)),
         None => {
             // rollback iterator, could be start of const declaration
             *tokens = before_start;
-            None
+            Ok(None)
         }
     }
 }
 
-fn consume_macro_inner(tokens: &mut TokenIter, attributes: Vec<Attribute>) -> Option<Macro> {
-    let name = consume_any_ident(tokens)?;
-    let tk_bang = consume_punct(tokens, '!')?;
+fn consume_macro_inner(
+    tokens: &mut TokenIter,
+    attributes: Vec<Attribute>,
+) -> Result<Option<Macro>, Error> {
+    let name = match consume_any_ident(tokens) {
+        Some(name) => name,
+        None => return Ok(None),
+    };
+    let tk_bang = match consume_punct(tokens, '!') {
+        Some(tk_bang) => tk_bang,
+        None => return Ok(None),
+    };
     let tk_declared_name = consume_any_ident(tokens);
 
-    let (is_paren, macro_body) = match tokens.next().expect("unexpected end of macro") {
-        TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => (true, group),
-        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => (false, group),
-        _ => panic!("cannot parse macro; missing `{{}}` or `()` group"),
+    let (is_paren, macro_body) = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            (true, group)
+        }
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => (false, group),
+        Some(token) => {
+            return Err(Error::new_at_span(
+                token.span(),
+                format!(
+                    "cannot parse macro: expected `{{ }}` or `( )`, found `{}`",
+                    token
+                ),
+            ));
+        }
+        None => {
+            return Err(Error::new_at_span(
+                tk_bang.span(),
+                "cannot parse macro: expected `{ }` or `( )`, found end of stream",
+            ));
+        }
     };
 
     let inner_tokens = macro_body.stream().into_iter().collect();
 
     let tk_semicolon = if is_paren {
-        Some(parse_punct(tokens, ';', "macro invocation semicolon"))
+        match tokens.next() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => Some(punct),
+            Some(token) => {
+                return Err(Error::new_at_span(
+                    token.span(),
+                    format!("cannot parse macro: expected `;`, found `{}`", token),
+                ));
+            }
+            None => {
+                return Err(Error::new_at_span(
+                    macro_body.span_close(),
+                    "cannot parse macro: expected `;`, found end of stream",
+                ));
+            }
+        }
     } else {
         None
     };
 
-    Some(Macro {
+    Ok(Some(Macro {
         attributes,
         name,
         tk_bang,
@@ -297,5 +390,5 @@ fn consume_macro_inner(tokens: &mut TokenIter, attributes: Vec<Attribute>) -> Op
         tk_braces_or_parens: GroupSpan::new(&macro_body),
         inner_tokens,
         tk_semicolon,
-    })
+    }))
 }
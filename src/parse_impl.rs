@@ -1,7 +1,11 @@
+use crate::error::Error;
+use crate::parse::consume_unknown_item;
 use crate::parse_extern::{parse_extern_block, parse_extern_crate};
 use crate::parse_fn::{consume_fn, consume_macro, NotFunction};
 use crate::parse_mod::parse_mod;
-use crate::parse_type::{consume_bound, consume_generic_params, consume_where_clause};
+use crate::parse_type::{
+    consume_bound, consume_generic_params, consume_item_name, consume_where_clause,
+};
 use crate::parse_utils::{
     consume_ident, consume_inner_attributes, consume_outer_attributes, consume_punct,
     consume_stuff_until, consume_vis_marker, parse_any_ident, parse_ident, parse_punct,
@@ -10,7 +14,7 @@ use crate::types::{
     Attribute, Constant, GroupSpan, Impl, ImplMember, Item, Trait, TraitMember, TypeAlias,
     TypeExpr, ValueExpr, VisMarker,
 };
-use proc_macro2::{Delimiter, Group, TokenTree};
+use proc_macro2::{Delimiter, Group, Ident, Punct, TokenTree};
 use quote::ToTokens;
 use std::iter::Peekable;
 
@@ -20,7 +24,7 @@ pub(crate) fn parse_const_or_static(
     tokens: &mut TokenIter,
     attributes: Vec<Attribute>,
     vis_marker: Option<VisMarker>,
-) -> Constant {
+) -> Result<Constant, Error> {
     let (tk_const_or_static, is_const) = match tokens.next() {
         Some(TokenTree::Ident(ident)) if ident == "const" => (ident, true),
         Some(TokenTree::Ident(ident)) if ident == "static" => (ident, false),
@@ -30,7 +34,9 @@ pub(crate) fn parse_const_or_static(
     let tk_mut = match tokens.peek() {
         Some(TokenTree::Ident(ident)) if ident == "mut" => {
             assert!(!is_const, "`const mut` is not a valid declaration");
-            Some(ident.clone())
+            let ident = ident.clone();
+            tokens.next();
+            Some(ident)
         }
         _ => None,
     };
@@ -41,7 +47,7 @@ pub(crate) fn parse_const_or_static(
     let ty_tokens = consume_stuff_until(
         tokens,
         |tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == '=' || punct.as_char() == ';'),
-        true,
+        false,
     );
 
     let tk_equals = consume_punct(tokens, '=');
@@ -49,7 +55,7 @@ pub(crate) fn parse_const_or_static(
     let value_tokens = consume_stuff_until(
         tokens,
         |tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == ';'),
-        true,
+        false,
     );
     let initializer = if value_tokens.is_empty() {
         None
@@ -59,9 +65,9 @@ pub(crate) fn parse_const_or_static(
         })
     };
 
-    let tk_semicolon = parse_punct(tokens, ';', "const/static");
+    let tk_semicolon = parse_semicolon(tokens, &name, "const/static")?;
 
-    Constant {
+    Ok(Constant {
         attributes,
         vis_marker,
         tk_const_or_static,
@@ -72,6 +78,24 @@ pub(crate) fn parse_const_or_static(
         tk_equals,
         initializer,
         tk_semicolon,
+    })
+}
+
+/// Parses the `;` ending an item, or returns an error pointing at the item name.
+fn parse_semicolon(tokens: &mut TokenIter, name: &Ident, context: &str) -> Result<Punct, Error> {
+    match tokens.next() {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => Ok(punct),
+        Some(token) => Err(Error::new_at_span(
+            token.span(),
+            format!("cannot parse {}: expected `;`, found `{}`", context, token),
+        )),
+        None => Err(Error::new_at_span(
+            name.span(),
+            format!(
+                "cannot parse {}: expected `;`, found end of stream",
+                context
+            ),
+        )),
     }
 }
 
@@ -79,41 +103,52 @@ pub(crate) fn consume_ty_definition(
     tokens: &mut TokenIter,
     attributes: Vec<Attribute>,
     vis_marker: Option<VisMarker>,
-) -> Option<TypeAlias> {
-    let context = "associated type";
+) -> Result<TypeAlias, Error> {
+    let context = "type alias";
     let tk_type = parse_ident(tokens, "type", context);
-    let name = parse_any_ident(tokens, context);
+    let name = consume_item_name(tokens)?;
+    let generic_params = consume_generic_params(tokens)?;
 
-    let bound = consume_bound(tokens, |token| {
-        matches!(
-            token,
-            TokenTree::Punct(punct) if punct.as_char() == '=' || punct.as_char() == ';'
-        )
+    let bound = consume_bound(tokens, |token| match token {
+        TokenTree::Punct(punct) if punct.as_char() == '=' || punct.as_char() == ';' => true,
+        TokenTree::Ident(ident) if ident == "where" => true,
+        _ => false,
     });
 
+    let where_clause = consume_where_clause(tokens)?;
+
     let tk_equals = consume_punct(tokens, '=');
 
     let initializer_ty = if tk_equals.is_some() {
         let ty_tokens = consume_stuff_until(
             tokens,
-            |tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == ';'),
-            true,
+            |tt| match tt {
+                TokenTree::Punct(punct) if punct.as_char() == ';' => true,
+                TokenTree::Ident(ident) if ident == "where" => true,
+                _ => false,
+            },
+            false,
         );
         Some(TypeExpr { tokens: ty_tokens })
     } else {
         None
     };
 
-    let tk_semicolon = parse_punct(tokens, ';', context);
+    let trailing_where_clause = consume_where_clause(tokens)?;
 
-    Some(TypeAlias {
+    let tk_semicolon = parse_semicolon(tokens, &name, context)?;
+
+    Ok(TypeAlias {
         attributes,
         vis_marker,
         tk_type,
         name,
+        generic_params,
         bound,
+        where_clause,
         tk_equals,
         initializer_ty,
+        trailing_where_clause,
         tk_semicolon,
     })
 }
@@ -124,41 +159,43 @@ pub(crate) fn consume_either_fn_type_const_static_impl(
     attributes: Vec<Attribute>,
     vis_marker: Option<VisMarker>,
     context: &str, // for panic
-) -> Item {
+    lenient: bool,
+) -> Result<Item, Error> {
     if let Some(TokenTree::Ident(ident)) = tokens.peek() {
         let keyword = ident.to_string();
-        match keyword.as_str() {
+        let item = match keyword.as_str() {
             "type" => {
-                let assoc_ty = consume_ty_definition(tokens, attributes, vis_marker);
-                Item::TypeAlias(assoc_ty.unwrap())
+                let assoc_ty = consume_ty_definition(tokens, attributes, vis_marker)?;
+                Item::TypeAlias(assoc_ty)
             }
 
             // Note: `static` is only used for extern "abi" {} blocks. Checked in call site.
             "default" | "const" | "static" | "async" | "unsafe" | "extern" | "fn" => {
-                match consume_fn(tokens, attributes.clone(), vis_marker.clone()) {
+                match consume_fn(tokens, attributes.clone(), vis_marker.clone())? {
                     Ok(method) => Item::Function(method),
                     Err(NotFunction::Const) => {
-                        let constant = parse_const_or_static(tokens, attributes, vis_marker);
+                        let constant = parse_const_or_static(tokens, attributes, vis_marker)?;
                         Item::Constant(constant)
                     }
                     Err(NotFunction::Static) => {
-                        let static_decl = parse_const_or_static(tokens, attributes, vis_marker);
+                        let static_decl = parse_const_or_static(tokens, attributes, vis_marker)?;
                         Item::Constant(static_decl)
                     }
                     Err(NotFunction::Trait) => {
-                        let trait_decl = parse_trait(tokens, attributes, vis_marker);
+                        let trait_decl = parse_trait(tokens, attributes, vis_marker, lenient)?;
                         Item::Trait(trait_decl)
                     }
                     Err(NotFunction::Impl) => {
-                        let impl_decl = parse_impl(tokens, attributes);
+                        let impl_decl = parse_impl(tokens, attributes, lenient)?;
                         Item::Impl(impl_decl)
                     }
                     Err(NotFunction::Mod) => {
-                        let mod_decl = parse_mod(tokens, attributes, vis_marker);
+                        let mod_decl = parse_mod(tokens, attributes, vis_marker, lenient)?;
                         Item::Module(mod_decl)
                     }
                     Err(NotFunction::ExternBlock) => {
-                        let extern_decl = parse_extern_block(tokens, attributes, vis_marker);
+                        let extern_decl =
+                            parse_extern_block(tokens, attributes, vis_marker, lenient)?;
                         Item::ExternBlock(extern_decl)
                     }
                     Err(NotFunction::ExternCrate) => {
@@ -167,6 +204,9 @@ pub(crate) fn consume_either_fn_type_const_static_impl(
                     }
                 }
             }
+            _ if lenient && vis_marker.is_some() => {
+                Item::Unknown(consume_unknown_item(tokens, attributes, vis_marker))
+            }
             ident => {
                 if let Some(vis_marker) = vis_marker {
                     panic!(
@@ -174,12 +214,20 @@ pub(crate) fn consume_either_fn_type_const_static_impl(
                         vis_marker.to_token_stream()
                     );
                 }
-                match consume_macro(tokens, attributes.clone()) {
+                match consume_macro(tokens, attributes.clone())? {
                     Some(macro_) => Item::Macro(macro_),
+                    None if lenient => {
+                        Item::Unknown(consume_unknown_item(tokens, attributes, None))
+                    }
                     None => panic!("unsupported {} item `{}`", context, ident),
                 }
             }
-        }
+        };
+        Ok(item)
+    } else if lenient && tokens.peek().is_some() {
+        Ok(Item::Unknown(consume_unknown_item(
+            tokens, attributes, vis_marker,
+        )))
     } else {
         panic!("unsupported {} element: {:?}", context, tokens.peek())
     }
@@ -188,7 +236,8 @@ pub(crate) fn consume_either_fn_type_const_static_impl(
 pub(crate) fn parse_impl_body(
     token_group: Group,
     allow_static: bool,
-) -> (GroupSpan, Vec<Attribute>, Vec<ImplMember>) {
+    lenient: bool,
+) -> Result<(GroupSpan, Vec<Attribute>, Vec<ImplMember>), Error> {
     let mut body_items = vec![];
 
     let mut tokens = token_group.stream().into_iter().peekable();
@@ -205,7 +254,8 @@ pub(crate) fn parse_impl_body(
             attributes,
             vis_marker,
             "impl",
-        ) {
+            lenient,
+        )? {
             Item::Function(function) => ImplMember::AssocFunction(function),
             Item::Constant(const_) if const_.tk_const_or_static == "const" => {
                 // `const` can appear in impl/trait blocks.
@@ -217,20 +267,32 @@ pub(crate) fn parse_impl_body(
             }
             Item::TypeAlias(ty_def) => ImplMember::AssocType(ty_def),
             Item::Macro(macro_) => ImplMember::Macro(macro_),
+            Item::Unknown(unknown) => ImplMember::Unknown(unknown),
+            Item::ExternCrate(crate_decl) => {
+                return Err(Error::new_at_tokens(
+                    &crate_decl,
+                    "`extern crate` is not allowed inside impl, trait or extern blocks",
+                ));
+            }
             _ => panic!("unsupported impl item `{:?}`", tokens.peek()),
         };
 
         body_items.push(item);
     }
 
-    (GroupSpan::new(&token_group), inner_attributes, body_items)
+    Ok((GroupSpan::new(&token_group), inner_attributes, body_items))
 }
 
-pub(crate) fn parse_impl(tokens: &mut TokenIter, attributes: Vec<Attribute>) -> Impl {
+pub(crate) fn parse_impl(
+    tokens: &mut TokenIter,
+    attributes: Vec<Attribute>,
+    lenient: bool,
+) -> Result<Impl, Error> {
     let tk_unsafe = consume_ident(tokens, "unsafe");
     let tk_impl = parse_ident(tokens, "impl", "impl block");
 
-    let impl_generic_params = consume_generic_params(tokens);
+    let impl_generic_params = consume_generic_params(tokens)?;
+    let tk_bang = consume_punct(tokens, '!');
     let trait_or_self_ty = consume_stuff_until(
         tokens,
         |tk| match tk {
@@ -238,7 +300,7 @@ pub(crate) fn parse_impl(tokens: &mut TokenIter, attributes: Vec<Attribute>) ->
             TokenTree::Ident(ident) if ident == "for" || ident == "where" => true,
             _ => false,
         },
-        true,
+        false,
     );
 
     let (tk_for, trait_ty, self_ty) = if let Some(tk_for) = consume_ident(tokens, "for") {
@@ -249,7 +311,7 @@ pub(crate) fn parse_impl(tokens: &mut TokenIter, attributes: Vec<Attribute>) ->
                 TokenTree::Ident(ident) if ident == "where" => true,
                 _ => false,
             },
-            true,
+            false,
         );
 
         (
@@ -269,20 +331,35 @@ pub(crate) fn parse_impl(tokens: &mut TokenIter, attributes: Vec<Attribute>) ->
         )
     };
 
-    let where_clause = consume_where_clause(tokens);
+    let where_clause = consume_where_clause(tokens)?;
 
-    let (tk_braces, inner_attributes, body_items) = match tokens.next().unwrap() {
-        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
-            parse_impl_body(group, false)
+    let (tk_braces, inner_attributes, body_items) = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+            parse_impl_body(group, false, lenient)?
+        }
+        Some(token) => {
+            return Err(Error::new_at_span(
+                token.span(),
+                format!(
+                    "cannot parse impl: expected body in `{{ }}`, found `{}`",
+                    token
+                ),
+            ));
+        }
+        None => {
+            return Err(Error::new_at_span(
+                tk_impl.span(),
+                "cannot parse impl: expected body in `{ }`, found end of stream",
+            ));
         }
-        token => panic!("cannot parse impl: unexpected token {:?}", token),
     };
 
-    Impl {
+    Ok(Impl {
         attributes,
         tk_unsafe,
         tk_impl,
         impl_generic_params,
+        tk_bang,
         trait_ty,
         tk_for,
         self_ty,
@@ -290,31 +367,47 @@ pub(crate) fn parse_impl(tokens: &mut TokenIter, attributes: Vec<Attribute>) ->
         body_items,
         inner_attributes,
         tk_braces,
-    }
+    })
 }
 
 pub(crate) fn parse_trait(
     tokens: &mut TokenIter,
     attributes: Vec<Attribute>,
     vis_marker: Option<VisMarker>,
-) -> Trait {
+    lenient: bool,
+) -> Result<Trait, Error> {
     let tk_unsafe = consume_ident(tokens, "unsafe");
+    let tk_auto = consume_ident(tokens, "auto");
     let tk_trait = parse_ident(tokens, "trait", "trait declaration");
-    let name = parse_any_ident(tokens, "trait name");
-    let generic_params = consume_generic_params(tokens);
+    let name = consume_item_name(tokens)?;
+    let generic_params = consume_generic_params(tokens)?;
     let bound = consume_bound(tokens, |token| match token {
         TokenTree::Ident(ident) if ident == "where" => true,
         TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => true,
         _ => false,
     });
-    let where_clause = consume_where_clause(tokens);
+    let where_clause = consume_where_clause(tokens)?;
 
     // For trait body, at the moment reuse impl parsing
-    let (tk_braces, inner_attributes, body_items) = match tokens.next().unwrap() {
-        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
-            parse_impl_body(group, false)
+    let (tk_braces, inner_attributes, body_items) = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+            parse_impl_body(group, false, lenient)?
+        }
+        Some(token) => {
+            return Err(Error::new_at_span(
+                token.span(),
+                format!(
+                    "cannot parse trait: expected body in `{{ }}`, found `{}`",
+                    token
+                ),
+            ));
+        }
+        None => {
+            return Err(Error::new_at_span(
+                name.span(),
+                "cannot parse trait: expected body in `{ }`, found end of stream",
+            ));
         }
-        token => panic!("cannot parse trait: unexpected token {:?}", token),
     };
 
     let body_items = body_items
@@ -324,13 +417,15 @@ pub(crate) fn parse_trait(
             ImplMember::AssocConstant(constant) => TraitMember::AssocConstant(constant),
             ImplMember::AssocType(assoc_ty) => TraitMember::AssocType(assoc_ty),
             ImplMember::Macro(macro_) => TraitMember::Macro(macro_),
+            ImplMember::Unknown(unknown) => TraitMember::Unknown(unknown),
         })
         .collect();
 
-    Trait {
+    Ok(Trait {
         attributes,
         vis_marker,
         tk_unsafe,
+        tk_auto,
         tk_trait,
         name,
         generic_params,
@@ -339,5 +434,5 @@ pub(crate) fn parse_trait(
         body_items,
         inner_attributes,
         tk_braces,
-    }
+    })
 }
@@ -0,0 +1,123 @@
+use crate::error::Error;
+use crate::parse_utils::{
+    consume_comma, consume_path, consume_punct, tokens_from_slice, TokenIter,
+};
+use crate::types::{GroupSpan, Meta, MetaList, Punctuated, ValueExpr};
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+
+/// Parses a comma-separated list of [`Meta`] items, such as `skip, rename = "x", with(path)`.
+pub(crate) fn parse_meta_list(tokens: TokenStream) -> Result<Punctuated<Meta>, Error> {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut items = Punctuated::new();
+    loop {
+        if tokens.peek().is_none() {
+            break;
+        }
+
+        let meta = parse_meta(&mut tokens)?;
+        let comma = consume_comma(&mut tokens);
+        match (&comma, tokens.peek()) {
+            (None, Some(token)) => {
+                return Err(Error::new_at_span(
+                    token.span(),
+                    format!(
+                        "cannot parse attribute arguments: expected `,`, found `{}`",
+                        token
+                    ),
+                ));
+            }
+            _ => items.push(meta, comma),
+        }
+    }
+
+    Ok(items)
+}
+
+fn parse_meta(tokens: &mut TokenIter) -> Result<Meta, Error> {
+    if let Some(TokenTree::Literal(literal)) = tokens.peek() {
+        let literal = literal.clone();
+        tokens.next();
+        return Ok(Meta::Lit(literal));
+    }
+
+    let mut path_tokens = vec![];
+    loop {
+        match tokens.peek() {
+            None => break,
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ',' || punct.as_char() == '=' => {
+                break
+            }
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => break,
+            Some(_) => path_tokens.push(tokens.next().unwrap()),
+        }
+    }
+
+    let span = path_tokens
+        .first()
+        .map_or_else(Span::call_site, |token| token.span());
+    let path = match consume_path(tokens_from_slice(&path_tokens)) {
+        Some(path) => path,
+        None if path_tokens.is_empty() => {
+            let found = match tokens.peek() {
+                Some(token) => format!("`{}`", token),
+                None => "end of stream".to_string(),
+            };
+            return Err(Error::new_at_span(
+                tokens.peek().map_or(span, |token| token.span()),
+                format!(
+                    "cannot parse attribute arguments: expected path or literal, found {}",
+                    found
+                ),
+            ));
+        }
+        None => {
+            return Err(Error::new_at_span(
+                span,
+                format!(
+                    "cannot parse attribute arguments: expected path, found `{}`",
+                    path_tokens.into_iter().collect::<TokenStream>()
+                ),
+            ));
+        }
+    };
+
+    if let Some(tk_equals) = consume_punct(tokens, '=') {
+        let mut value_tokens = vec![];
+        loop {
+            match tokens.peek() {
+                None => break,
+                Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => break,
+                Some(_) => value_tokens.push(tokens.next().unwrap()),
+            }
+        }
+
+        if value_tokens.is_empty() {
+            return Err(Error::new_at_span(
+                tk_equals.span(),
+                "cannot parse attribute arguments: expected value after `=`",
+            ));
+        }
+
+        return Ok(Meta::NameValue {
+            path,
+            tk_equals,
+            value: ValueExpr {
+                tokens: value_tokens,
+            },
+        });
+    }
+
+    match tokens.peek() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            let group = group.clone();
+            tokens.next();
+
+            Ok(Meta::List(MetaList {
+                path,
+                tk_parens: GroupSpan::new(&group),
+                items: parse_meta_list(group.stream())?,
+            }))
+        }
+        _ => Ok(Meta::Path(path)),
+    }
+}
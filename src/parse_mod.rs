@@ -1,30 +1,47 @@
-use crate::parse::consume_item;
+use crate::error::Error;
+use crate::parse::consume_item_with_mode;
 use crate::parse_type::consume_item_name;
 use crate::parse_utils::{
-    consume_ident, consume_inner_attributes, consume_stuff_until, parse_ident, parse_punct,
-    TokenIter,
+    consume_colon2, consume_comma, consume_ident, consume_inner_attributes, parse_ident, TokenIter,
+};
+use crate::{
+    Attribute, GroupSpan, Module, Punctuated, UseDeclaration, UseGroup, UsePath, UseRename,
+    UseTree, VisMarker,
 };
-use crate::{Attribute, GroupSpan, Module, TypeExpr, UseDeclaration, VisMarker};
 use proc_macro2::token_stream::IntoIter;
-use proc_macro2::{Delimiter, TokenTree};
+use proc_macro2::{Delimiter, Ident, TokenTree};
 use std::iter::Peekable;
 
 pub(crate) fn parse_mod(
     tokens: &mut Peekable<IntoIter>,
     attributes: Vec<Attribute>,
     vis_marker: Option<VisMarker>,
-) -> Module {
+    lenient: bool,
+) -> Result<Module, Error> {
     let tk_unsafe = consume_ident(tokens, "unsafe");
     let tk_mod = parse_ident(tokens, "mod", "module declaration");
-    let module_name = consume_item_name(tokens);
+    let module_name = consume_item_name(tokens)?;
 
-    let (group, tk_semicolon) = match tokens.next().unwrap() {
-        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => (Some(group), None),
-        TokenTree::Punct(punct) if punct.as_char() == ';' => (None, Some(punct)),
-        token => panic!(
-            "cannot parse mod: expected `{{ }}` or `;`, but got token {:?}",
-            token
-        ),
+    let (group, tk_semicolon) = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+            (Some(group), None)
+        }
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => (None, Some(punct)),
+        Some(token) => {
+            return Err(Error::new_at_span(
+                token.span(),
+                format!(
+                    "cannot parse mod: expected `{{ }}` or `;`, found `{}`",
+                    token
+                ),
+            ));
+        }
+        None => {
+            return Err(Error::new_at_span(
+                module_name.span(),
+                "cannot parse mod: expected `{ }` or `;`, found end of stream",
+            ));
+        }
     };
 
     let inner_attributes;
@@ -41,8 +58,7 @@ pub(crate) fn parse_mod(
             if tokens.peek().is_none() {
                 break;
             }
-            let item =
-                consume_item(&mut tokens).unwrap_or_else(|e| panic!("declaration in mod: {}", e));
+            let item = consume_item_with_mode(&mut tokens, lenient)?;
             mod_members.push(item);
         }
         members = mod_members;
@@ -52,7 +68,7 @@ pub(crate) fn parse_mod(
         members = vec![];
     }
 
-    Module {
+    Ok(Module {
         attributes,
         vis_marker,
         tk_unsafe,
@@ -62,34 +78,124 @@ pub(crate) fn parse_mod(
         tk_braces,
         inner_attributes,
         members,
-    }
+    })
 }
 
 pub(crate) fn parse_use_declaration(
     tokens: &mut TokenIter,
     attributes: Vec<Attribute>,
     vis_marker: Option<VisMarker>,
-) -> UseDeclaration {
+) -> Result<UseDeclaration, Error> {
     let tk_use = parse_ident(tokens, "use", "use declaration");
+    let tk_leading_colons = consume_colon2(tokens);
+    let tree = parse_use_tree(tokens, &tk_use)?;
 
-    let import_tree = consume_stuff_until(
-        tokens,
-        |token| match token {
-            TokenTree::Punct(punct) if punct.as_char() == ';' => true,
-            _ => false,
-        },
-        true,
-    );
-
-    let tk_semicolon = parse_punct(tokens, ';', "use declaration");
+    let tk_semicolon = match tokens.next() {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => punct,
+        Some(token) => {
+            return Err(Error::new_at_span(
+                token.span(),
+                format!(
+                    "cannot parse use declaration: expected `;`, found `{}`",
+                    token
+                ),
+            ));
+        }
+        None => {
+            return Err(Error::new_at_span(
+                tk_use.span(),
+                "cannot parse use declaration: expected `;`, found end of stream",
+            ));
+        }
+    };
 
-    UseDeclaration {
+    Ok(UseDeclaration {
         attributes,
         vis_marker,
         tk_use,
-        import_tree: TypeExpr {
-            tokens: import_tree,
-        },
+        tk_leading_colons,
+        tree,
         tk_semicolon,
+    })
+}
+
+/// Parses a (possibly nested) use tree such as `std::collections::{self, HashMap as Map}`.
+///
+/// `tk_use` is only used for error reporting.
+fn parse_use_tree(tokens: &mut TokenIter, tk_use: &Ident) -> Result<UseTree, Error> {
+    match tokens.next() {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '*' => Ok(UseTree::Glob(punct)),
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+            let mut items = Punctuated::new();
+            let mut group_tokens = group.stream().into_iter().peekable();
+            loop {
+                if group_tokens.peek().is_none() {
+                    break;
+                }
+
+                let item = parse_use_tree(&mut group_tokens, tk_use)?;
+                let comma = consume_comma(&mut group_tokens);
+                match (&comma, group_tokens.peek()) {
+                    (None, Some(token)) => {
+                        return Err(Error::new_at_span(
+                            token.span(),
+                            format!(
+                                "cannot parse use declaration: expected `,`, found `{}`",
+                                token
+                            ),
+                        ));
+                    }
+                    _ => items.push(item, comma),
+                }
+            }
+
+            Ok(UseTree::Group(UseGroup {
+                tk_braces: GroupSpan::new(&group),
+                items,
+            }))
+        }
+        Some(TokenTree::Ident(ident)) => {
+            if let Some(tk_colon2) = consume_colon2(tokens) {
+                let tree = parse_use_tree(tokens, tk_use)?;
+                Ok(UseTree::Path(UsePath {
+                    ident,
+                    tk_colon2,
+                    tree: Box::new(tree),
+                }))
+            } else if let Some(tk_as) = consume_ident(tokens, "as") {
+                // Note: `_` is an identifier, so `Trait as _` is covered too
+                match tokens.next() {
+                    Some(TokenTree::Ident(rename)) => Ok(UseTree::Rename(UseRename {
+                        ident,
+                        tk_as,
+                        rename,
+                    })),
+                    Some(token) => Err(Error::new_at_span(
+                        token.span(),
+                        format!(
+                            "cannot parse use declaration: expected identifier after `as`, found `{}`",
+                            token
+                        ),
+                    )),
+                    None => Err(Error::new_at_span(
+                        tk_as.span(),
+                        "cannot parse use declaration: expected identifier after `as`, found end of stream",
+                    )),
+                }
+            } else {
+                Ok(UseTree::Name(ident))
+            }
+        }
+        Some(token) => Err(Error::new_at_span(
+            token.span(),
+            format!(
+                "cannot parse use declaration: expected identifier, `*` or `{{`, found `{}`",
+                token
+            ),
+        )),
+        None => Err(Error::new_at_span(
+            tk_use.span(),
+            "cannot parse use declaration: expected identifier, `*` or `{`, found end of stream",
+        )),
     }
 }
@@ -1,28 +1,37 @@
 use crate::error::Error;
 use crate::parse_utils::{
-    consume_colon2, consume_comma, consume_ident, consume_outer_attributes, consume_punct,
-    consume_stuff_until, consume_vis_marker, parse_any_ident, parse_punct,
+    consume_comma, consume_ident, consume_outer_attributes, consume_punct, consume_stuff_until,
+    consume_vis_marker, parse_any_ident, parse_punct, tokens_from_slice,
 };
 use crate::types::{
     EnumVariant, EnumVariantValue, Fields, GenericArg, GenericArgList, GenericBound, GenericParam,
-    GenericParamList, GroupSpan, Lifetime, NamedField, NamedFields, Punctuated, TupleField,
-    TupleFields, TypeExpr, WhereClause, WhereClausePredicate,
+    GenericParamDefault, GenericParamList, GroupSpan, Lifetime, NamedField, NamedFields,
+    Punctuated, TupleField, TupleFields, TypeExpr, ValueExpr, WhereClause, WhereClausePredicate,
 };
-use proc_macro2::{Delimiter, Group, Ident, Punct, TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, TokenStream, TokenTree};
 use std::iter::Peekable;
 
 type TokenIter = Peekable<proc_macro2::token_stream::IntoIter>;
 
-pub(crate) fn consume_item_name(tokens: &mut TokenIter) -> Ident {
-    parse_any_ident(tokens, "item")
+pub(crate) fn consume_item_name(tokens: &mut TokenIter) -> Result<Ident, Error> {
+    match tokens.next() {
+        Some(TokenTree::Ident(ident)) => Ok(ident),
+        Some(token) => Err(Error::new_at_span(
+            token.span(),
+            format!("cannot parse item: expected identifier, found `{}`", token),
+        )),
+        None => Err(Error::new(
+            "cannot parse item: expected identifier, found end of stream",
+        )),
+    }
 }
 
 pub(crate) fn consume_bound(
     tokens: &mut TokenIter,
     mut end_predicate: impl FnMut(&TokenTree) -> bool,
 ) -> Option<GenericBound> {
-    match tokens.peek().unwrap() {
-        TokenTree::Punct(punct) if punct.as_char() == ':' => {
+    match tokens.peek() {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ':' => {
             let colon = punct.clone();
             // consume ':'
             tokens.next();
@@ -34,49 +43,142 @@ pub(crate) fn consume_bound(
                 tokens: bound_tokens,
             })
         }
-        token if end_predicate(token) => None,
-        token => {
+        Some(token) if end_predicate(token) => None,
+        // End of stream is reported by the caller
+        None => None,
+        Some(token) => {
             panic!("cannot parse generic bound: unexpected token {:?}", token)
         }
     }
 }
 
-pub(crate) fn consume_generic_params(tokens: &mut TokenIter) -> Option<GenericParamList> {
+/// Splits bound tokens such as `Clone + Fn(A) -> B + 'a` on their top-level `+` signs.
+///
+/// A trailing `+` is kept as trailing punctuation. Empty bounds, e.g. from `Clone + + Send`, are skipped.
+pub(crate) fn parse_bound_list(tokens: &[TokenTree]) -> Punctuated<TypeExpr> {
+    let mut bounds = Punctuated::new();
+
+    let mut tokens = tokens_from_slice(tokens);
+    loop {
+        if tokens.peek().is_none() {
+            break;
+        }
+
+        let bound_tokens = consume_stuff_until(
+            &mut tokens,
+            |token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == '+'),
+            false,
+        );
+        let plus = consume_punct(&mut tokens, '+');
+        if bound_tokens.is_empty() {
+            continue;
+        }
+
+        bounds.push(
+            TypeExpr {
+                tokens: bound_tokens,
+            },
+            plus,
+        );
+    }
+
+    bounds
+}
+
+pub(crate) fn consume_generic_params(
+    tokens: &mut TokenIter,
+) -> Result<Option<GenericParamList>, Error> {
     let mut generic_params = Punctuated::new();
 
-    let gt = consume_punct(tokens, '<')?;
+    let gt = match consume_punct(tokens, '<') {
+        Some(gt) => gt,
+        None => return Ok(None),
+    };
 
     let lt: Punct;
     loop {
-        let token = tokens
-            .peek()
-            .expect("cannot parse generic params: expected token after '<'");
-        let prefix = match token {
-            TokenTree::Punct(punct) if punct.as_char() == '>' => {
+        let prefix = match tokens.peek() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '>' => {
                 lt = punct.clone();
                 break;
             }
-            TokenTree::Punct(punct) if punct.as_char() == '\'' => Some(tokens.next().unwrap()),
-            TokenTree::Ident(ident) if ident == "const" => Some(tokens.next().unwrap()),
-            TokenTree::Ident(_ident) => None,
-            token => {
-                panic!("cannot parse generic params: unexpected token {:?}", token)
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '\'' => {
+                Some(tokens.next().unwrap())
+            }
+            Some(TokenTree::Ident(ident)) if ident == "const" => Some(tokens.next().unwrap()),
+            Some(TokenTree::Ident(_ident)) => None,
+            Some(token) => {
+                return Err(Error::new_at_span(
+                    token.span(),
+                    format!("cannot parse generic params: unexpected token `{}`", token),
+                ));
+            }
+            None => {
+                return Err(Error::new_at_span(
+                    gt.span(),
+                    "cannot parse generic params: expected `>`, found end of stream",
+                ));
             }
         };
 
-        let name = parse_any_ident(tokens, "generic param name");
+        let name = match tokens.next() {
+            Some(TokenTree::Ident(ident)) => ident,
+            Some(token) => {
+                return Err(Error::new_at_span(
+                    token.span(),
+                    format!(
+                        "cannot parse generic params: expected identifier, found `{}`",
+                        token
+                    ),
+                ));
+            }
+            None => {
+                return Err(Error::new_at_span(
+                    gt.span(),
+                    "cannot parse generic params: expected identifier, found end of stream",
+                ));
+            }
+        };
 
-        let bound = consume_bound(
-            tokens,
-            |token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ',' || punct.as_char() == '>'),
-        );
+        let is_const = matches!(&prefix, Some(TokenTree::Ident(ident)) if ident == "const");
+        let is_param_end = |token: &TokenTree| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ',' || punct.as_char() == '>' || punct.as_char() == '=');
+
+        let (const_ty, bound) = if is_const {
+            let const_ty = consume_punct(tokens, ':').map(|tk_colon| {
+                let ty_tokens = consume_stuff_until(tokens, is_param_end, false);
+                (tk_colon, TypeExpr { tokens: ty_tokens })
+            });
+            (const_ty, None)
+        } else {
+            (None, consume_bound(tokens, is_param_end))
+        };
+
+        let default = consume_punct(tokens, '=').map(|tk_equals| {
+            let default_tokens = consume_stuff_until(
+                tokens,
+                |token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ',' || punct.as_char() == '>'),
+                false,
+            );
+            let default = if is_const {
+                GenericParamDefault::Const(ValueExpr {
+                    tokens: default_tokens,
+                })
+            } else {
+                GenericParamDefault::Type(TypeExpr {
+                    tokens: default_tokens,
+                })
+            };
+            (tk_equals, default)
+        });
         let comma = consume_comma(tokens);
 
         generic_params.push(
             GenericParam {
                 tk_prefix: prefix,
                 name,
+                const_ty,
                 bound,
+                default,
             },
             comma,
         );
@@ -85,50 +187,73 @@ pub(crate) fn consume_generic_params(tokens: &mut TokenIter) -> Option<GenericPa
     // consume '>'
     tokens.next();
 
-    Some(GenericParamList {
+    Ok(Some(GenericParamList {
         tk_l_bracket: gt,
         params: generic_params,
         tk_r_bracket: lt,
-    })
+    }))
 }
 
-fn parse_generic_arg(tokens: Vec<TokenTree>) -> GenericArg {
+fn parse_generic_arg(tokens: Vec<TokenTree>) -> Result<GenericArg, Error> {
     // Note: method not called if tokens is empty
     let mut tokens = tokens.into_iter().peekable();
 
-    if let Some(lifetime) = consume_lifetime(&mut tokens, true) {
-        return GenericArg::Lifetime { lifetime };
+    if let Some(lifetime) = consume_lifetime(&mut tokens) {
+        return match tokens.next() {
+            None => Ok(GenericArg::Lifetime { lifetime }),
+            Some(token) => Err(Error::new_at_span(
+                token.span(),
+                format!(
+                    "cannot parse lifetime generic argument: expected `,` or `>`, found `{}`",
+                    token
+                ),
+            )),
+        };
     }
 
-    // Then, try parsing Item = ...
+    // Then, try parsing Item = ... or Item: ...
     // (there is at least 1 token, so unwrap is safe)
     // TODO also handle generic bindings (eg `LendingIterator<Item<'_> = XXX>`)
     let before_ident = tokens.clone();
     if let TokenTree::Ident(ident) = tokens.next().unwrap() {
-        if let Some(TokenTree::Punct(punct)) = tokens.next() {
-            if punct.as_char() == '=' {
+        match tokens.next() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '=' => {
                 let remaining: Vec<TokenTree> = tokens.collect();
 
-                return GenericArg::Binding {
+                return Ok(GenericArg::Binding {
                     ident,
                     tk_equals: punct,
                     ty: TypeExpr { tokens: remaining },
-                };
+                });
             }
+            // A joint `:` is the start of a `::` path separator
+            Some(TokenTree::Punct(punct))
+                if punct.as_char() == ':' && punct.spacing() == Spacing::Alone =>
+            {
+                let remaining: Vec<TokenTree> = tokens.collect();
+
+                return Ok(GenericArg::AssocBound {
+                    ident,
+                    bound: GenericBound {
+                        tk_colon: punct,
+                        tokens: remaining,
+                    },
+                });
+            }
+            _ => (),
         }
     }
 
     // Last, all the rest is just tokens
     let remaining: Vec<TokenTree> = before_ident.collect();
 
-    GenericArg::TypeOrConst {
+    Ok(GenericArg::TypeOrConst {
         expr: TypeExpr { tokens: remaining },
-    }
+    })
 }
 
 pub(crate) fn consume_lifetime(
     tokens: &mut Peekable<impl Iterator<Item = TokenTree>>,
-    expect_end: bool,
 ) -> Option<Lifetime> {
     // Try parsing 'lifetime
     let tk_apostrophe = match tokens.peek() {
@@ -142,19 +267,10 @@ pub(crate) fn consume_lifetime(
 
     // after the ', there must be a single identifier
     match tokens.next() {
-        Some(TokenTree::Ident(name)) => {
-            if expect_end {
-                assert!(
-                    tokens.next().is_none(),
-                    "cannot parse lifetime generic argument"
-                );
-            }
-
-            Some(Lifetime {
-                tk_apostrophe,
-                name,
-            })
-        }
+        Some(TokenTree::Ident(name)) => Some(Lifetime {
+            tk_apostrophe,
+            name,
+        }),
         Some(other) => {
             panic!(
                 "expected identifier after ' lifetime symbol, got {:?}",
@@ -167,22 +283,14 @@ pub(crate) fn consume_lifetime(
     }
 }
 
-pub(crate) fn consume_generic_args(tokens: &mut TokenIter) -> Option<GenericArgList> {
-    // TODO consider multiple-lookahead instead of potentially cloning many tokens
-    let before_start = tokens.clone();
-    let tk_turbofish_colons = consume_colon2(tokens);
-
-    let tk_l_bracket = match tokens.peek() {
-        Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => {
-            let gt = punct.clone();
-            tokens.next();
-            gt
-        }
-        _ => {
-            *tokens = before_start;
-            return None;
-        }
-    };
+/// Parses a generic argument list, such as `<T, 'a>`, given the optional turbofish `::` before it.
+///
+/// Panics if the next token is not `<`.
+pub(crate) fn parse_generic_args(
+    tokens: &mut TokenIter,
+    tk_turbofish_colons: Option<[Punct; 2]>,
+) -> Result<GenericArgList, Error> {
+    let tk_l_bracket = parse_punct(tokens, '<', "generic argument list");
 
     let mut generic_args = Punctuated::new();
     loop {
@@ -199,12 +307,27 @@ pub(crate) fn consume_generic_args(tokens: &mut TokenIter) -> Option<GenericArgL
             break;
         }
 
-        generic_args.push(parse_generic_arg(arg_tokens), comma);
+        generic_args.push(parse_generic_arg(arg_tokens)?, comma);
     }
 
-    let tk_r_bracket = parse_punct(tokens, '>', "end of generic argument list");
+    let tk_r_bracket = match consume_punct(tokens, '>') {
+        Some(tk_r_bracket) => tk_r_bracket,
+        None => {
+            let (span, found) = match tokens.peek() {
+                Some(token) => (token.span(), format!("`{}`", token)),
+                None => (tk_l_bracket.span(), "end of stream".to_string()),
+            };
+            return Err(Error::new_at_span(
+                span,
+                format!(
+                    "cannot parse generic arguments: expected `>`, found {}",
+                    found
+                ),
+            ));
+        }
+    };
 
-    Some(GenericArgList {
+    Ok(GenericArgList {
         tk_turbofish_colons,
         tk_l_bracket,
         args: generic_args,
@@ -212,18 +335,29 @@ pub(crate) fn consume_generic_args(tokens: &mut TokenIter) -> Option<GenericArgL
     })
 }
 
-pub(crate) fn consume_where_clause(tokens: &mut TokenIter) -> Option<WhereClause> {
-    let where_token = consume_ident(tokens, "where")?;
+pub(crate) fn consume_where_clause(tokens: &mut TokenIter) -> Result<Option<WhereClause>, Error> {
+    let where_token = match consume_ident(tokens, "where") {
+        Some(where_token) => where_token,
+        None => return Ok(None),
+    };
 
     let mut items = Punctuated::new();
     loop {
-        let token = tokens
-            .peek()
-            .expect("cannot parse where clause: expected tokens");
+        let token = match tokens.peek() {
+            Some(token) => token,
+            None => {
+                return Err(Error::new_at_span(
+                    where_token.span(),
+                    "cannot parse where clause: expected `{ }`, `;` or `=` after predicates, found end of stream",
+                ));
+            }
+        };
 
         match token {
             TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => break,
             TokenTree::Punct(punct) if punct.as_char() == ';' => break,
+            // `=` of a type alias with a where clause before it
+            TokenTree::Punct(punct) if punct.as_char() == '=' => break,
             _ => (),
         };
 
@@ -238,12 +372,17 @@ pub(crate) fn consume_where_clause(tokens: &mut TokenIter) -> Option<WhereClause
 
         let colon = match tokens.next() {
             Some(TokenTree::Punct(punct)) if punct.as_char() == ':' => punct.clone(),
-            Some(token) => panic!(
-                "cannot parse where clause: expected ':', found token {:?}",
-                token
-            ),
+            Some(token) => {
+                return Err(Error::new_at_span(
+                    token.span(),
+                    format!("cannot parse where clause: expected `:`, found `{}`", token),
+                ));
+            }
             None => {
-                panic!("cannot parse where clause: expected colon, found end of stream")
+                return Err(Error::new_at_span(
+                    where_token.span(),
+                    "cannot parse where clause: expected `:`, found end of stream",
+                ));
             }
         };
         let bound_tokens = consume_stuff_until(
@@ -251,10 +390,10 @@ pub(crate) fn consume_where_clause(tokens: &mut TokenIter) -> Option<WhereClause
             |token| match token {
                 TokenTree::Punct(punct) if punct.as_char() == ',' => true,
                 TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => true,
-                TokenTree::Punct(punct) if punct.as_char() == ';' => true,
+                TokenTree::Punct(punct) if punct.as_char() == ';' || punct.as_char() == '=' => true,
                 _ => false,
             },
-            true,
+            false,
         );
 
         let comma = consume_comma(tokens);
@@ -271,13 +410,13 @@ pub(crate) fn consume_where_clause(tokens: &mut TokenIter) -> Option<WhereClause
         );
     }
 
-    Some(WhereClause {
+    Ok(Some(WhereClause {
         tk_where: where_token,
         items,
-    })
+    }))
 }
 
-pub(crate) fn consume_field_type(tokens: &mut TokenIter) -> Vec<TokenTree> {
+pub(crate) fn consume_field_type(tokens: &mut TokenIter) -> Result<Vec<TokenTree>, Error> {
     let field_type_tokens = consume_stuff_until(
         tokens,
         |token| match token {
@@ -287,13 +426,19 @@ pub(crate) fn consume_field_type(tokens: &mut TokenIter) -> Vec<TokenTree> {
         false,
     );
 
-    if field_type_tokens.is_empty() && consume_comma(tokens).is_some() {
-        panic!("cannot parse type: unexpected token ','");
-    } else if field_type_tokens.is_empty() {
-        panic!("cannot parse type: expected tokens, found end-of-stream");
+    if field_type_tokens.is_empty() {
+        return match tokens.peek() {
+            Some(token) => Err(Error::new_at_span(
+                token.span(),
+                format!("cannot parse type: unexpected token `{}`", token),
+            )),
+            None => Err(Error::new(
+                "cannot parse type: expected tokens, found end of stream",
+            )),
+        };
     }
 
-    field_type_tokens
+    Ok(field_type_tokens)
 }
 
 pub(crate) fn consume_enum_discriminant(
@@ -305,25 +450,64 @@ pub(crate) fn consume_enum_discriminant(
         return Ok(None);
     };
 
-    let value_token = tokens.next().unwrap();
+    // Commas inside a turbofish or qualified path, e.g. `foo::<A, B>()` or `<T as Tr<A, B>>::X`, don't end
+    // the value. Angle brackets are only counted after `::` or `as`, or at the start of the value, so that
+    // comparisons and shifts such as `1 << 3` are left alone.
+    let mut value_tokens: Vec<TokenTree> = Vec::new();
+    let mut turbofish_depth = 0;
+    loop {
+        let token = match tokens.peek() {
+            None => break,
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ',' && turbofish_depth == 0 => {
+                break
+            }
+            Some(_) => tokens.next().unwrap(),
+        };
 
-    // If the value expression has more than one token, we output an error.
-    match tokens.peek() {
-        None => (),
-        Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => (),
-        Some(_token) => {
-            let span = value_token.span();
-            return Err(Error::new_at_span(span,"Complex values for enum variants are not supported unless they are between parentheses."));
+        if let TokenTree::Punct(punct) = &token {
+            let after_colon2 = matches!(
+                value_tokens.as_slice(),
+                [.., TokenTree::Punct(first), TokenTree::Punct(second)]
+                    if first.as_char() == ':' && first.spacing() == Spacing::Joint && second.as_char() == ':'
+            );
+            let opens_type = value_tokens.is_empty()
+                || matches!(value_tokens.last(), Some(TokenTree::Ident(ident)) if ident == "as");
+            let after_arrow_minus = matches!(
+                value_tokens.last(),
+                Some(TokenTree::Punct(minus)) if minus.as_char() == '-' && minus.spacing() == Spacing::Joint
+            );
+            match punct.as_char() {
+                '<' if turbofish_depth > 0 || after_colon2 || opens_type => turbofish_depth += 1,
+                '>' if turbofish_depth > 0 && !after_arrow_minus => turbofish_depth -= 1,
+                _ => {}
+            }
         }
+        value_tokens.push(token);
     }
 
-    Ok(Some(EnumVariantValue {
-        tk_equal,
-        value: value_token,
-    }))
+    // A single token is stored as-is; longer expressions are wrapped in an invisible group,
+    // which also preserves their precedence when the value is quoted elsewhere.
+    let value = match value_tokens.len() {
+        0 => {
+            return Err(Error::new_at_span(
+                tk_equal.span(),
+                "cannot parse enum variant: expected discriminant value after `=`",
+            ));
+        }
+        1 => value_tokens.pop().unwrap(),
+        _ => {
+            let first_span = value_tokens[0].span();
+            let last_span = value_tokens[value_tokens.len() - 1].span();
+            let mut group = Group::new(Delimiter::None, value_tokens.iter().cloned().collect());
+            group.set_span(first_span.join(last_span).unwrap_or(first_span));
+            TokenTree::Group(group)
+        }
+    };
+
+    Ok(Some(EnumVariantValue { tk_equal, value }))
 }
 
-pub(crate) fn parse_tuple_fields(token_group: Group) -> TupleFields {
+pub(crate) fn parse_tuple_fields(token_group: Group) -> Result<TupleFields, Error> {
     let mut fields = Punctuated::new();
 
     let mut tokens = token_group.stream().into_iter().peekable();
@@ -335,7 +519,7 @@ pub(crate) fn parse_tuple_fields(token_group: Group) -> TupleFields {
         let attributes = consume_outer_attributes(&mut tokens);
         let vis_marker = consume_vis_marker(&mut tokens);
 
-        let ty_tokens = consume_field_type(&mut tokens);
+        let ty_tokens = consume_field_type(&mut tokens)?;
 
         let comma = consume_comma(&mut tokens);
 
@@ -349,14 +533,18 @@ pub(crate) fn parse_tuple_fields(token_group: Group) -> TupleFields {
         );
     }
 
-    TupleFields {
+    Ok(TupleFields {
         fields,
         tk_parens: GroupSpan::new(&token_group),
-    }
+    })
 }
 
-pub(crate) fn parse_named_fields(token_group: Group) -> NamedFields {
+/// Parses all fields, even after encountering malformed ones.
+///
+/// If any fields are malformed, the errors of all of them are combined into the returned error.
+pub(crate) fn parse_named_fields(token_group: Group) -> Result<NamedFields, Error> {
     let mut fields = Punctuated::new();
+    let mut errors: Option<Error> = None;
 
     let mut tokens = token_group.stream().into_iter().peekable();
     loop {
@@ -364,31 +552,100 @@ pub(crate) fn parse_named_fields(token_group: Group) -> NamedFields {
             break;
         }
 
-        let attributes = consume_outer_attributes(&mut tokens);
-        let vis_marker = consume_vis_marker(&mut tokens);
+        match consume_named_field(&mut tokens, &token_group) {
+            Ok(field) => {
+                let comma = consume_comma(&mut tokens);
+                fields.push(field, comma);
+            }
+            Err(error) => {
+                match &mut errors {
+                    Some(errors) => errors.combine(error),
+                    None => errors = Some(error),
+                }
+
+                // Skip the rest of the malformed field, and resume after the next comma
+                consume_stuff_until(
+                    &mut tokens,
+                    |token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','),
+                    false,
+                );
+                consume_comma(&mut tokens);
+            }
+        }
+    }
 
-        let field_name = parse_any_ident(&mut tokens, "field name");
-        let colon = parse_punct(&mut tokens, ':', "named fields");
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(NamedFields {
+            fields,
+            tk_braces: GroupSpan::new(&token_group),
+        }),
+    }
+}
 
-        let ty_tokens = consume_field_type(&mut tokens);
-        let comma = consume_comma(&mut tokens);
+fn consume_named_field(tokens: &mut TokenIter, token_group: &Group) -> Result<NamedField, Error> {
+    let attributes = consume_outer_attributes(tokens);
+    let vis_marker = consume_vis_marker(tokens);
 
-        fields.push(
-            NamedField {
-                attributes,
-                vis_marker,
-                name: field_name,
-                tk_colon: colon,
-                ty: TypeExpr { tokens: ty_tokens },
-            },
-            comma,
-        );
-    }
+    // Offending tokens are only peeked, so that a `,` is left for the caller's error recovery
+    let field_name = match tokens.peek() {
+        Some(TokenTree::Ident(ident)) => {
+            let ident = ident.clone();
+            tokens.next();
+            ident
+        }
+        Some(token) => {
+            return Err(Error::new_at_span(
+                token.span(),
+                format!(
+                    "cannot parse named fields: expected field name, found `{}`",
+                    token
+                ),
+            ));
+        }
+        None => {
+            return Err(Error::new_at_span(
+                token_group.span_close(),
+                "cannot parse named fields: expected field name, found end of stream",
+            ));
+        }
+    };
 
-    NamedFields {
-        fields,
-        tk_braces: GroupSpan::new(&token_group),
-    }
+    let colon = match tokens.peek() {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ':' => {
+            let punct = punct.clone();
+            tokens.next();
+            punct
+        }
+        Some(token) => {
+            return Err(Error::new_at_span(
+                token.span(),
+                format!(
+                    "cannot parse field `{}`: expected `:`, found `{}`",
+                    field_name, token
+                ),
+            ));
+        }
+        None => {
+            return Err(Error::new_at_span(
+                field_name.span(),
+                format!(
+                    "cannot parse field `{}`: expected `:`, found end of stream",
+                    field_name
+                ),
+            ));
+        }
+    };
+
+    let ty_tokens = consume_field_type(tokens)?;
+
+    Ok(NamedField {
+        attributes,
+        vis_marker,
+        name: field_name,
+        tk_colon: colon,
+        ty: TypeExpr { tokens: ty_tokens },
+    })
 }
 
 pub(crate) fn parse_enum_variants(tokens: TokenStream) -> Result<Punctuated<EnumVariant>, Error> {
@@ -413,15 +670,23 @@ pub(crate) fn parse_enum_variants(tokens: TokenStream) -> Result<Punctuated<Enum
                 let group = group.clone();
                 // Consume group
                 tokens.next();
-                Fields::Tuple(parse_tuple_fields(group))
+                Fields::Tuple(parse_tuple_fields(group)?)
             }
             Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
                 let group = group.clone();
                 // Consume group
                 tokens.next();
-                Fields::Named(parse_named_fields(group))
+                Fields::Named(parse_named_fields(group)?)
+            }
+            Some(token) => {
+                return Err(Error::new_at_span(
+                    token.span(),
+                    format!(
+                        "cannot parse enum variant `{}`: expected `,`, `=`, `( )` or `{{ }}`, found `{}`",
+                        variant_name, token
+                    ),
+                ));
             }
-            token => panic!("cannot parse enum variant: unexpected token {:?}", token),
         };
 
         let enum_discriminant = consume_enum_discriminant(&mut tokens);
@@ -1,6 +1,8 @@
-use crate::parse_type::consume_generic_args;
-use crate::types::{Attribute, AttributeValue, GroupSpan, Path, PathSegment, VisMarker};
-use proc_macro2::{Delimiter, Ident, Punct, Spacing, TokenStream, TokenTree};
+use crate::parse_type::parse_generic_args;
+use crate::types::{
+    Attribute, AttributeValue, CfgPredicate, GroupSpan, Path, PathSegment, VisMarker,
+};
+use proc_macro2::{Delimiter, Ident, Literal, Punct, Spacing, TokenStream, TokenTree};
 use std::iter::Peekable;
 
 pub(crate) type TokenIter = Peekable<proc_macro2::token_stream::IntoIter>;
@@ -170,6 +172,51 @@ fn consume_attributes_with_inner(tokens: &mut TokenIter, expect_inner: bool) ->
     attributes
 }
 
+/// Configuration predicate inside `#[cfg(...)]`, e.g. `all(unix, feature = "std")`.
+///
+/// Returns None if the tokens are not a valid predicate.
+pub(crate) fn consume_cfg_predicate(tokens: &mut TokenIter) -> Option<CfgPredicate> {
+    let name = consume_any_ident(tokens)?;
+
+    match tokens.peek() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            let mut inner_tokens = group.stream().into_iter().peekable();
+            tokens.next();
+
+            let mut predicates = vec![];
+            while inner_tokens.peek().is_some() {
+                predicates.push(consume_cfg_predicate(&mut inner_tokens)?);
+                if consume_comma(&mut inner_tokens).is_none() {
+                    break;
+                }
+            }
+            if inner_tokens.peek().is_some() {
+                return None;
+            }
+
+            match name.to_string().as_str() {
+                "all" => Some(CfgPredicate::All(predicates)),
+                "any" => Some(CfgPredicate::Any(predicates)),
+                "not" if predicates.len() == 1 => {
+                    Some(CfgPredicate::Not(Box::new(predicates.pop().unwrap())))
+                }
+                _ => None,
+            }
+        }
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '=' => {
+            tokens.next();
+            match tokens.next() {
+                Some(TokenTree::Literal(literal)) => Some(CfgPredicate::KeyValue(
+                    name,
+                    string_literal_value(&literal)?,
+                )),
+                _ => None,
+            }
+        }
+        _ => Some(CfgPredicate::Option(name)),
+    }
+}
+
 /// Outer macro attributes of the form `#[attribute]`
 ///
 /// Panics if any inner attributes such as `#![attribute]` are encountered.
@@ -237,6 +284,8 @@ pub(crate) fn consume_stuff_until(
                 bracket_count -= 1;
                 false
             }
+            // `>` of a `->` arrow is neither a closing bracket nor a separator
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '>' => false,
             Some(TokenTree::Punct(punct)) if punct.as_char() == '-' => true,
             Some(token) if predicate(token) && bracket_count == 0 => {
                 predicate_met = true;
@@ -280,21 +329,29 @@ pub(crate) fn consume_comma(tokens: &mut TokenIter) -> Option<Punct> {
 ///
 /// Does not advance `tokens` if the double colon is not found.
 pub(crate) fn consume_colon2(tokens: &mut TokenIter) -> Option<[Punct; 2]> {
+    match tokens.peek() {
+        Some(TokenTree::Punct(first))
+            if first.as_char() == ':' && first.spacing() == Spacing::Joint => {}
+        _ => return None,
+    }
+
+    // A joint colon can still be followed by something else, as in `T:'a`.
+    // Only clone the iterator in that rare case.
     // TODO consider multiple-lookahead instead of potentially cloning many tokens
     let before_start = tokens.clone();
-
-    if let Some(TokenTree::Punct(first)) = tokens.next() {
-        if first.as_char() == ':' && first.spacing() == Spacing::Joint {
-            if let Some(TokenTree::Punct(second)) = tokens.next() {
-                if second.as_char() == ':' && second.spacing() == Spacing::Alone {
-                    return Some([first, second]);
-                }
-            }
+    let first = match tokens.next() {
+        Some(TokenTree::Punct(first)) => first,
+        _ => unreachable!(),
+    };
+
+    // Second colon is joint if directly followed by another punct, as in `::<` or `::*`
+    match tokens.next() {
+        Some(TokenTree::Punct(second)) if second.as_char() == ':' => Some([first, second]),
+        _ => {
+            *tokens = before_start;
+            None
         }
     }
-
-    *tokens = before_start;
-    None
 }
 
 /// Tries to parse a path expressions; returns `None` if not matching.
@@ -311,7 +368,17 @@ pub(crate) fn consume_path(mut tokens: TokenIter) -> Option<Path> {
             _ => return None, // end of tokens OR not a path
         };
 
-        let generic_args = consume_generic_args(&mut tokens);
+        // `::` is either a turbofish or the separator to the next segment.
+        // Deciding here avoids the rollback in consume_generic_args().
+        let mut tk_colons = consume_colon2(&mut tokens);
+        let generic_args = match tokens.peek() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => {
+                let generic_args = parse_generic_args(&mut tokens, tk_colons.take()).ok()?;
+                tk_colons = consume_colon2(&mut tokens);
+                Some(generic_args)
+            }
+            _ => None,
+        };
 
         segments.push(PathSegment {
             tk_separator_colons,
@@ -319,17 +386,108 @@ pub(crate) fn consume_path(mut tokens: TokenIter) -> Option<Path> {
             generic_args,
         });
 
-        if tokens.peek().is_none() {
-            break;
-        }
-
         // Intermediate `::` are not optional
-        if let Some(separator) = consume_colon2(&mut tokens) {
-            tk_separator_colons = Some(separator);
-        } else {
-            return None;
+        match tk_colons {
+            Some(separator) => tk_separator_colons = Some(separator),
+            None if tokens.peek().is_none() => break,
+            None => return None,
         }
     }
 
     Some(Path { segments })
 }
+
+/// Returns the unescaped content of a string literal such as `"a\nb"` or `r#"a"b"#`.
+///
+/// Returns None for other literals, including byte strings and C strings.
+pub(crate) fn string_literal_value(literal: &Literal) -> Option<String> {
+    let repr = literal.to_string();
+
+    if let Some(raw) = repr.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let content = raw
+            .get(hashes..raw.len().checked_sub(hashes)?)?
+            .strip_prefix('"')?
+            .strip_suffix('"')?;
+        return Some(content.to_string());
+    }
+
+    let content = repr.strip_prefix('"')?.strip_suffix('"')?;
+    let mut value = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => value.push('\n'),
+            'r' => value.push('\r'),
+            't' => value.push('\t'),
+            '0' => value.push('\0'),
+            '\\' => value.push('\\'),
+            '\'' => value.push('\''),
+            '"' => value.push('"'),
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                value.push(u8::from_str_radix(&hex, 16).ok()? as char);
+            }
+            'u' => {
+                let hex: String = chars
+                    .by_ref()
+                    .skip(1) // `{`
+                    .take_while(|&c| c != '}')
+                    .filter(|&c| c != '_')
+                    .collect();
+                value.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+            }
+            // Line continuation: skip the newline and leading whitespace of the next line
+            '\n' => while chars.next_if(|c| c.is_whitespace()).is_some() {},
+            _ => return None,
+        }
+    }
+    Some(value)
+}
+
+/// Primitive integer types, as used in literal suffixes and `#[repr(...)]`.
+pub(crate) const INT_SUFFIXES: [&str; 12] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Returns the value of an unsigned integer literal such as `42`, `0x2A` or `1_000u32`.
+///
+/// Returns None for other literals, or if the value doesn't fit into `u64`.
+pub(crate) fn int_literal_value(literal: &Literal) -> Option<u64> {
+    let repr = literal.to_string().replace('_', "");
+
+    let (digits, radix) = match repr.get(..2) {
+        Some("0x") => (&repr[2..], 16),
+        Some("0o") => (&repr[2..], 8),
+        Some("0b") => (&repr[2..], 2),
+        _ => (repr.as_str(), 10),
+    };
+
+    // Strip type suffix such as `u8` or `usize`; `u` and `i` are not hex digits, so this is unambiguous
+    let suffix_start = digits.find(['u', 'i']).unwrap_or(digits.len());
+    let (digits, suffix) = digits.split_at(suffix_start);
+    let valid_suffix = suffix.is_empty() || INT_SUFFIXES.contains(&suffix);
+    if digits.is_empty() || !valid_suffix {
+        return None;
+    }
+
+    u64::from_str_radix(digits, radix).ok()
+}
+
+/// Returns the value of an integer literal, possibly negated, such as `42`, `0x2A` or `-1`.
+///
+/// Returns None for other tokens, or if the value doesn't fit into `i64`.
+pub(crate) fn signed_int_value(tokens: &[TokenTree]) -> Option<i64> {
+    match tokens {
+        [TokenTree::Literal(literal)] => i64::try_from(int_literal_value(literal)?).ok(),
+        [TokenTree::Punct(minus), TokenTree::Literal(literal)] if minus.as_char() == '-' => {
+            i64::try_from(-i128::from(int_literal_value(literal)?)).ok()
+        }
+        _ => None,
+    }
+}
@@ -3,7 +3,10 @@ use quote::{ToTokens, TokenStreamExt as _};
 
 // Inspired by syn's `Punctuated` type.
 
-/// Comma-separated list of items.
+/// List of items separated by punctuation, usually commas.
+///
+/// Dereferences to a slice of `(item, punct)` pairs, so slice methods such as `iter()`, `first()` or indexing
+/// are available and yield pairs. Use [`Self::items()`] and [`Self::items_mut()`] to access the items only.
 #[derive(Clone)]
 pub struct Punctuated<T> {
     /// Vec of items and commas.
@@ -32,6 +35,14 @@ impl<T> Punctuated<T> {
         self.inner.push((value, comma))
     }
 
+    /// Add an item at the end of the list, without trailing comma.
+    ///
+    /// The previous item keeps its comma, which has call-site span unless it was
+    /// pushed with an explicit one. Same as `push(value, None)`.
+    pub fn push_value(&mut self, value: T) {
+        self.push(value, None);
+    }
+
     /// Inserts an element at position `index`.
     ///
     /// # Panics
@@ -49,11 +60,23 @@ impl<T> Punctuated<T> {
         }
     }
 
+    /// Remove all items for which `f` returns false.
+    ///
+    /// The remaining items keep their separators, and there is a trailing separator only if the original list had one.
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        self.inner.retain(|(item, _punct)| f(item));
+    }
+
     /// Return an interator that reads items.
     pub fn items(&self) -> impl Iterator<Item = &T> {
         self.inner.iter().map(|(item, _punct)| item)
     }
 
+    /// Return an iterator that modifies items in place.
+    pub fn items_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.inner.iter_mut().map(|(item, _punct)| item)
+    }
+
     /// Return an interator that reads commas.
     pub fn punct(&self) -> impl Iterator<Item = &Punct> {
         let len = self.inner.len();
@@ -65,6 +88,82 @@ impl<T> Punctuated<T> {
         slice.iter().map(|(_item, punct)| punct)
     }
 
+    /// Return an iterator over items and their separators.
+    ///
+    /// The separator is None for the last item, if it has no trailing punctuation.
+    pub fn pairs(&self) -> impl Iterator<Item = (&T, Option<&Punct>)> {
+        let last_index = self.last_index_without_punct();
+        self.inner
+            .iter()
+            .enumerate()
+            .map(move |(i, (item, punct))| {
+                let punct = if Some(i) != last_index {
+                    Some(punct)
+                } else {
+                    None
+                };
+                (item, punct)
+            })
+    }
+
+    /// Return an iterator over owned items and their separators.
+    ///
+    /// The separator is None for the last item, if it has no trailing punctuation.
+    pub fn into_pairs(self) -> impl Iterator<Item = (T, Option<Punct>)> {
+        let last_index = self.last_index_without_punct();
+        self.inner
+            .into_iter()
+            .enumerate()
+            .map(move |(i, (item, punct))| {
+                let punct = if Some(i) != last_index {
+                    Some(punct)
+                } else {
+                    None
+                };
+                (item, punct)
+            })
+    }
+
+    /// Transform each item, keeping the separators.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Punctuated<U> {
+        Punctuated {
+            inner: self
+                .inner
+                .into_iter()
+                .map(|(item, punct)| (f(item), punct))
+                .collect(),
+            skip_last: self.skip_last,
+        }
+    }
+
+    /// Transform each item, dropping those for which `f` returns None.
+    ///
+    /// The remaining items keep their separators, and there is a trailing separator only if the original list had one.
+    pub fn filter_map<U>(self, mut f: impl FnMut(T) -> Option<U>) -> Punctuated<U> {
+        Punctuated {
+            inner: self
+                .inner
+                .into_iter()
+                .filter_map(|(item, punct)| Some((f(item)?, punct)))
+                .collect(),
+            skip_last: self.skip_last,
+        }
+    }
+
+    fn last_index_without_punct(&self) -> Option<usize> {
+        match self.inner.len() {
+            len if self.skip_last && len > 0 => Some(len - 1),
+            _ => None,
+        }
+    }
+
+    /// Return true if the last item is followed by a separator, e.g. `a, b,` as opposed to `a, b`.
+    ///
+    /// Always false for an empty list. [`Self::push()`] and [`Self::push_value()`] keep this up to date.
+    pub fn has_trailing_punct(&self) -> bool {
+        !self.inner.is_empty() && !self.skip_last
+    }
+
     /// Return number of items.
     pub fn len(&self) -> usize {
         self.inner.len()
@@ -111,6 +210,42 @@ impl<T: ToTokens> ToTokens for Punctuated<T> {
     }
 }
 
+/// Items are separated by commas with call-site span, without trailing comma.
+impl<T> FromIterator<T> for Punctuated<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut punctuated = Self::new();
+        punctuated.extend(iter);
+        punctuated
+    }
+}
+
+/// Items are separated by commas with call-site span, without trailing comma.
+impl<T> Extend<T> for Punctuated<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_value(item);
+        }
+    }
+}
+
+impl<T> IntoIterator for Punctuated<T> {
+    type Item = T;
+    type IntoIter = std::iter::Map<std::vec::IntoIter<(T, Punct)>, fn((T, Punct)) -> T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter().map(|(item, _punct)| item)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Punctuated<T> {
+    type Item = &'a T;
+    type IntoIter = std::iter::Map<std::slice::Iter<'a, (T, Punct)>, fn(&'a (T, Punct)) -> &'a T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter().map(|(item, _punct)| item)
+    }
+}
+
 impl<T> std::ops::Deref for Punctuated<T> {
     type Target = [(T, Punct)];
 
@@ -0,0 +1,57 @@
+//! Conversions between venial and [syn](https://docs.rs/syn) types, enabled by the `syn` feature.
+//!
+//! Conversions go through token streams, so they are not faster than parsing from scratch.
+//! They are meant for codebases migrating incrementally from one crate to the other.
+
+use crate::error::Error;
+use crate::parse::parse_item;
+use crate::types::{Enum, Function, Item, Struct, Union};
+use quote::ToTokens;
+
+macro_rules! implement_syn_conversions {
+    ($Kind:ident, $SynKind:ty, $as_kind:ident, $name:literal) => {
+        impl TryFrom<$Kind> for $SynKind {
+            type Error = syn::Error;
+
+            fn try_from(value: $Kind) -> Result<Self, Self::Error> {
+                syn::parse2(value.to_token_stream())
+            }
+        }
+
+        impl TryFrom<$SynKind> for $Kind {
+            type Error = Error;
+
+            fn try_from(value: $SynKind) -> Result<Self, Self::Error> {
+                let item = parse_item(value.to_token_stream())?;
+                match item.$as_kind() {
+                    Some(converted) => Ok(converted.clone()),
+                    None => Err(Error::new_at_tokens(
+                        &item,
+                        concat!("cannot convert from syn: expected ", $name),
+                    )),
+                }
+            }
+        }
+    };
+}
+
+implement_syn_conversions!(Struct, syn::ItemStruct, as_struct, "struct");
+implement_syn_conversions!(Enum, syn::ItemEnum, as_enum, "enum");
+implement_syn_conversions!(Union, syn::ItemUnion, as_union, "union");
+implement_syn_conversions!(Function, syn::ItemFn, as_function, "function");
+
+impl TryFrom<Item> for syn::Item {
+    type Error = syn::Error;
+
+    fn try_from(value: Item) -> Result<Self, Self::Error> {
+        syn::parse2(value.to_token_stream())
+    }
+}
+
+impl TryFrom<syn::Item> for Item {
+    type Error = Error;
+
+    fn try_from(value: syn::Item) -> Result<Self, Self::Error> {
+        parse_item(value.to_token_stream())
+    }
+}
@@ -1,10 +1,15 @@
-use crate::{parse_item, GenericParam, Item, Struct, TypeExpr, WhereClausePredicate};
-
-use crate::parse_type::consume_generic_args;
+use crate::{
+    consume_item_lenient, parse_attribute_macro, parse_item, parse_item_lenient, parse_items,
+    parse_path, parse_type, CfgPredicate, Error, Fields, FnParam, GenericArg, GenericParam,
+    GenericParamKind, ImplMember, Item, Meta, Punctuated, ReprHint, Struct, TraitMember, TypeExpr,
+    UseTree, VisMarker, VisRestriction, WhereClause, WhereClausePredicate,
+};
+
+use crate::parse_utils::consume_colon2;
 use crate::types::GenericArgList;
 use insta::assert_debug_snapshot;
-use proc_macro2::{Delimiter, TokenStream};
-use quote::quote;
+use proc_macro2::{Delimiter, Punct, Spacing, Span, TokenStream};
+use quote::{quote, ToTokens};
 
 // TODO - check test coverage
 
@@ -29,7 +34,9 @@ fn parse_generic_args_checked(tokens: TokenStream) -> GenericArgList {
     let initial_tokens = tokens.clone();
 
     let mut token_iter = tokens.into_iter().peekable();
-    let generic_args = consume_generic_args(&mut token_iter).unwrap();
+    let tk_turbofish_colons = consume_colon2(&mut token_iter);
+    let generic_args =
+        crate::parse_type::parse_generic_args(&mut token_iter, tk_turbofish_colons).unwrap();
 
     similar_asserts::assert_eq!(
         quote!(#generic_args).to_string(),
@@ -73,6 +80,38 @@ fn parse_normal_struct() {
     assert_debug_snapshot!(struct_type);
 }
 
+#[test]
+fn struct_shapes() {
+    let unit_struct = parse_struct_declaration(quote!(
+        struct Hello;
+    ));
+    assert!(unit_struct.is_unit_struct());
+    assert!(!unit_struct.is_tuple_struct() && !unit_struct.is_named_struct());
+    assert_eq!(unit_struct.field_count(), 0);
+
+    let tuple_struct = parse_struct_declaration(quote!(
+        struct Hello(A, B);
+    ));
+    assert!(tuple_struct.is_tuple_struct());
+    assert!(!tuple_struct.is_unit_struct() && !tuple_struct.is_named_struct());
+    assert_eq!(tuple_struct.field_count(), 2);
+
+    let named_struct = parse_struct_declaration(quote!(
+        struct Hello {
+            a: A,
+        }
+    ));
+    assert!(named_struct.is_named_struct());
+    assert!(!named_struct.is_unit_struct() && !named_struct.is_tuple_struct());
+    assert_eq!(named_struct.field_count(), 1);
+
+    let empty_struct = parse_struct_declaration(quote!(
+        struct Hello {}
+    ));
+    assert!(empty_struct.is_named_struct() && !empty_struct.is_unit_struct());
+    assert_eq!(empty_struct.field_count(), 0);
+}
+
 #[test]
 fn parse_enum() {
     let enum_type = parse_declaration_checked(quote!(
@@ -86,6 +125,103 @@ fn parse_enum() {
     assert_debug_snapshot!(enum_type);
 }
 
+#[test]
+fn enum_variant_shapes() {
+    let enum_type = parse_declaration_checked(quote!(
+        enum Hello {
+            A,
+            B(Foo, Bar),
+            C { foo: Foo, bar: Bar },
+        }
+    ));
+    let enum_type = enum_type.as_enum().unwrap();
+    assert_eq!(enum_type.variant_count(), 3);
+    let variants: Vec<_> = enum_type.variants().collect();
+
+    assert!(variants[0].is_unit() && !variants[0].is_tuple() && !variants[0].is_named());
+    assert!(variants[1].is_tuple() && !variants[1].is_unit() && !variants[1].is_named());
+    assert!(variants[2].is_named() && !variants[2].is_unit() && !variants[2].is_tuple());
+
+    let field_types: Vec<Vec<_>> = variants
+        .iter()
+        .map(|variant| {
+            variant
+                .fields_iter()
+                .map(|ty| ty.to_token_stream().to_string())
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        field_types,
+        [vec![], vec!["Foo", "Bar"], vec!["Foo", "Bar"]]
+    );
+}
+
+#[test]
+fn fields_as_types() {
+    let struct_type = parse_declaration_checked(quote!(
+        struct Hello(Foo, pub Bar<T>, (A, B));
+    ));
+    let fields = &struct_type.as_struct().unwrap().fields;
+    let types: Vec<_> = fields
+        .as_types()
+        .iter()
+        .map(|ty| ty.to_token_stream().to_string())
+        .collect();
+    assert_eq!(types, ["Foo", "Bar < T >", "(A , B)"]);
+    assert_eq!(fields.field_count(), 3);
+
+    let struct_type = parse_declaration_checked(quote!(
+        struct Hello;
+    ));
+    let fields = &struct_type.as_struct().unwrap().fields;
+    assert!(fields.as_types().is_empty());
+    assert_eq!(fields.field_count(), 0);
+}
+
+#[test]
+fn tuple_fields_binding_names() {
+    let enum_type = parse_declaration_checked(quote!(
+        enum Hello {
+            A(Foo, Bar<T>),
+        }
+    ));
+    let variant = &enum_type.as_enum().unwrap().variants[0].0;
+    let fields = match &variant.fields {
+        Fields::Tuple(fields) => fields,
+        _ => unreachable!(),
+    };
+
+    let names = fields.binding_names("__field");
+    let names: Vec<_> = names.iter().map(|name| name.to_string()).collect();
+    assert_eq!(names, ["__field0", "__field1"]);
+}
+
+#[test]
+fn fields_members() {
+    let struct_type = parse_declaration_checked(quote!(
+        struct Hello {
+            a: Foo,
+            b: Bar,
+        }
+    ));
+    let fields = &struct_type.as_struct().unwrap().fields;
+    let members = fields.members();
+    assert_eq!(quote!(#(self.#members)*).to_string(), "self . a self . b");
+
+    let struct_type = parse_declaration_checked(quote!(
+        struct Hello(Foo, Bar);
+    ));
+    let fields = &struct_type.as_struct().unwrap().fields;
+    let members = fields.members();
+    assert_eq!(quote!(#(self.#members)*).to_string(), "self . 0 self . 1");
+
+    let struct_type = parse_declaration_checked(quote!(
+        struct Hello;
+    ));
+    assert_eq!(struct_type.as_struct().unwrap().fields.members().count(), 0);
+}
+
 #[test]
 fn parse_union() {
     let union_type = parse_declaration_checked(quote!(
@@ -136,6 +272,522 @@ fn reject_trailing_tokens() {
     println!("This should have panicked: {:#?}", declaration);
 }
 
+#[test]
+fn parse_unknown_items_leniently() {
+    let tokens = quote! {
+        struct Known;
+        #[attr]
+        pub unsupported Foo { bar }
+        future_keyword x = 5;
+        fn known() {}
+    };
+
+    let mut token_iter = tokens.clone().into_iter().peekable();
+    let mut items = vec![];
+    while token_iter.peek().is_some() {
+        items.push(consume_item_lenient(&mut token_iter).unwrap());
+    }
+
+    assert_eq!(items.len(), 4);
+    assert!(matches!(items[0], Item::Struct(_)));
+    match &items[1] {
+        Item::Unknown(unknown) => {
+            assert_eq!(unknown.attributes.len(), 1);
+            assert!(unknown.vis_marker.is_some());
+            let unknown_tokens = &unknown.tokens;
+            assert_eq!(
+                quote!(#(#unknown_tokens)*).to_string(),
+                "unsupported Foo { bar }"
+            );
+        }
+        other => panic!("expected unknown item, got {:?}", other),
+    }
+    match &items[2] {
+        Item::Unknown(unknown) => assert_eq!(unknown.tokens.len(), 5),
+        other => panic!("expected unknown item, got {:?}", other),
+    }
+    assert!(matches!(items[3], Item::Function(_)));
+    assert!(items[1].name().is_none());
+
+    similar_asserts::assert_eq!(quote!(#(#items)*).to_string(), tokens.to_string());
+}
+
+#[test]
+fn parse_nested_unknown_items_leniently() {
+    let tokens = quote! {
+        mod m {
+            foo bar;
+            impl S {
+                fn known() {}
+                baz qux;
+            }
+            trait T {
+                pub unsupported X { y }
+            }
+        }
+    };
+
+    let item = parse_item_lenient(tokens.clone()).unwrap();
+    let module = item.as_module().unwrap();
+    assert_eq!(module.members.len(), 3);
+
+    let Item::Unknown(unknown) = &module.members[0] else {
+        panic!("expected unknown item, got {:?}", module.members[0])
+    };
+    assert_eq!(unknown.tokens.len(), 3);
+
+    let impl_decl = module.members[1].as_impl().unwrap();
+    assert!(matches!(
+        impl_decl.body_items[0],
+        ImplMember::AssocFunction(_)
+    ));
+    assert!(matches!(impl_decl.body_items[1], ImplMember::Unknown(_)));
+
+    let trait_decl = module.members[2].as_trait().unwrap();
+    match &trait_decl.body_items[0] {
+        TraitMember::Unknown(unknown) => assert!(unknown.vis_marker.is_some()),
+        other => panic!("expected unknown member, got {:?}", other),
+    }
+
+    similar_asserts::assert_eq!(quote!(#item).to_string(), tokens.to_string());
+}
+
+#[test]
+#[should_panic = "cannot parse declaration"]
+fn reject_unknown_item() {
+    let _ = parse_item(quote!(unsupported Foo {}));
+}
+
+#[test]
+fn parse_item_sequence() {
+    let tokens = quote! {
+        const A: u8 = 1;
+        struct B { x: u8 }
+        macro_rules! c { () => {} }
+        c!();
+        mod d;
+        impl B { fn e(&self) {} }
+    };
+    let items = parse_items(tokens.clone()).unwrap();
+    let names: Vec<_> = items
+        .iter()
+        .map(|item| item.name().map(|name| name.to_string()))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            Some("A".to_string()),
+            Some("B".to_string()),
+            Some("macro_rules".to_string()),
+            Some("c".to_string()),
+            Some("d".to_string()),
+            None
+        ]
+    );
+    similar_asserts::assert_eq!(quote!(#(#items)*).to_string(), tokens.to_string());
+
+    assert!(parse_items(TokenStream::new()).unwrap().is_empty());
+
+    let error = parse_items(quote!(struct A; struct 42;)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse item: expected identifier, found `42`"
+    );
+}
+
+#[test]
+fn item_attributes_and_vis_marker() {
+    let items = parse_items(quote! {
+        #[a] pub struct A;
+        #[b] #[c] pub(crate) enum B {}
+        fn c() {}
+        #[d] impl C {}
+    })
+    .unwrap();
+
+    let attribute_counts: Vec<_> = items.iter().map(|item| item.attributes().len()).collect();
+    assert_eq!(attribute_counts, [1, 2, 0, 1]);
+
+    let vis_markers: Vec<_> = items
+        .iter()
+        .map(|item| {
+            item.vis_marker()
+                .map(|vis| vis.to_token_stream().to_string())
+        })
+        .collect();
+    assert_eq!(
+        vis_markers,
+        [
+            Some("pub".to_string()),
+            Some("pub (crate)".to_string()),
+            None,
+            None
+        ]
+    );
+}
+
+#[test]
+fn item_name_span() {
+    let item = parse_item(quote!(
+        struct Hello;
+    ))
+    .unwrap();
+    assert_eq!(item.name().unwrap(), "Hello");
+    assert!(item.name_span().is_some());
+
+    let item = parse_item(quote!(impl Hello {})).unwrap();
+    assert!(item.name().is_none());
+    assert!(item.name_span().is_none());
+}
+
+#[test]
+fn try_from_token_stream() {
+    let struct_type: Struct = quote!(
+        struct Hello(i32);
+    )
+    .try_into()
+    .unwrap();
+    assert_eq!(struct_type.name, "Hello");
+
+    let item = Item::try_from(quote!(
+        fn hello() {}
+    ))
+    .unwrap();
+    assert!(item.as_function().is_some());
+
+    let parse_function = |tokens: TokenStream| -> Result<crate::Function, Error> {
+        let function: crate::Function = tokens.try_into()?;
+        Ok(function)
+    };
+    assert!(parse_function(quote!(
+        fn hello() {}
+    ))
+    .is_ok());
+    assert_eq!(
+        parse_function(quote!(
+            struct Hello;
+        ))
+        .unwrap_err()
+        .to_string(),
+        "cannot parse function: expected function, found struct"
+    );
+    assert!(parse_function(quote!(struct 42;)).is_err());
+}
+
+#[test]
+fn reject_invalid_item_name() {
+    let struct_error = parse_item(quote!(struct 42;)).unwrap_err();
+    assert_eq!(
+        struct_error.to_string(),
+        "cannot parse item: expected identifier, found `42`"
+    );
+
+    let fn_error = parse_item(quote!(fn (a: A) {})).unwrap_err();
+    assert_eq!(
+        fn_error.to_string(),
+        "cannot parse item: expected identifier, found `(a : A)`"
+    );
+
+    let nested_error = parse_item(quote!(
+        mod foo {
+            impl Foo {
+                const fn {}
+            }
+        }
+    ))
+    .unwrap_err();
+    assert_eq!(
+        nested_error.to_string(),
+        "cannot parse item: expected identifier, found `{ }`"
+    );
+}
+
+#[test]
+fn error_to_compile_error() {
+    let error = parse_item(quote!(struct 42;)).unwrap_err();
+    assert_eq!(
+        error.to_compile_error().to_string(),
+        quote!(compile_error! { "cannot parse item: expected identifier, found `42`" }).to_string()
+    );
+
+    let error = Error::new_at_span(Span::call_site(), "custom error");
+    assert_eq!(
+        error.to_compile_error().to_string(),
+        quote!(compile_error! { "custom error" }).to_string()
+    );
+}
+
+#[test]
+fn reject_invalid_fields() {
+    let colon_error = parse_item(quote!(
+        struct Foo {
+            a: A,
+            b B,
+        }
+    ))
+    .unwrap_err();
+    assert_eq!(
+        colon_error.to_string(),
+        "cannot parse field `b`: expected `:`, found `B`"
+    );
+
+    let name_error = parse_item(quote!(
+        union Foo {
+            a: A,
+            'b: B,
+        }
+    ))
+    .unwrap_err();
+    assert_eq!(
+        name_error.to_string(),
+        "cannot parse named fields: expected field name, found `'`"
+    );
+
+    let type_error = parse_item(quote!(
+        enum Foo {
+            A(u8, , u16),
+        }
+    ))
+    .unwrap_err();
+    assert_eq!(
+        type_error.to_string(),
+        "cannot parse type: unexpected token `,`"
+    );
+}
+
+#[test]
+fn reject_multiple_invalid_fields() {
+    let error = parse_item(quote!(
+        struct Foo {
+            a A,
+            b: B,
+            c: ,
+            d: D,
+            e = E,
+        }
+    ))
+    .unwrap_err();
+
+    let expected = quote!(
+        compile_error! { "cannot parse field `a`: expected `:`, found `A`" }
+        compile_error! { "cannot parse type: unexpected token `,`" }
+        compile_error! { "cannot parse field `e`: expected `:`, found `=`" }
+    );
+    assert_eq!(error.to_compile_error().to_string(), expected.to_string());
+}
+
+#[test]
+fn reject_invalid_fields_ending_at_comma() {
+    let error = parse_item(quote!(
+        struct Foo {
+            a,
+            b,
+            c: C,
+        }
+    ))
+    .unwrap_err();
+
+    let expected = quote!(
+        compile_error! { "cannot parse field `a`: expected `:`, found `,`" }
+        compile_error! { "cannot parse field `b`: expected `:`, found `,`" }
+    );
+    assert_eq!(error.to_compile_error().to_string(), expected.to_string());
+
+    let error = parse_item(quote!(
+        struct Foo {
+            a: ,
+            b,
+            , c: C,
+        }
+    ))
+    .unwrap_err();
+
+    let expected = quote!(
+        compile_error! { "cannot parse type: unexpected token `,`" }
+        compile_error! { "cannot parse field `b`: expected `:`, found `,`" }
+        compile_error! { "cannot parse named fields: expected field name, found `,`" }
+    );
+    assert_eq!(error.to_compile_error().to_string(), expected.to_string());
+}
+
+#[test]
+fn reject_invalid_mod_body() {
+    let paren_error = parse_item(quote!(mod foo ())).unwrap_err();
+    assert_eq!(
+        paren_error.to_string(),
+        "cannot parse mod: expected `{ }` or `;`, found `()`"
+    );
+
+    let nested_error = parse_item(quote!(
+        mod outer {
+            mod inner
+        }
+    ))
+    .unwrap_err();
+    assert_eq!(
+        nested_error.to_string(),
+        "cannot parse mod: expected `{ }` or `;`, found end of stream"
+    );
+}
+
+#[test]
+fn reject_invalid_union_body() {
+    let tuple_error = parse_item(quote!(union Foo(u32, f32);)).unwrap_err();
+    assert_eq!(
+        tuple_error.to_string(),
+        "cannot parse union: expected named fields in `{ }`, found `(u32 , f32)`"
+    );
+
+    let unit_error = parse_item(quote!(union Foo;)).unwrap_err();
+    assert_eq!(
+        unit_error.to_string(),
+        "cannot parse union: expected named fields in `{ }`, found `;`"
+    );
+
+    let missing_error = parse_item(quote!(union Foo)).unwrap_err();
+    assert_eq!(
+        missing_error.to_string(),
+        "cannot parse union: expected named fields in `{ }`, found end of stream"
+    );
+}
+
+#[test]
+#[rustfmt::skip] // would remove empty `<>`
+fn parse_empty_generic_params() {
+    for item in [
+        quote!(struct Foo<> {}),
+        quote!(struct Foo<>(i32);),
+        quote!(enum Foo<> { A }),
+        quote!(fn foo<>() {}),
+        quote!(trait Foo<> {}),
+        quote!(type Foo<> = i32;),
+    ] {
+        let item = parse_declaration_checked(item);
+        let generic_params = item.generic_params().expect("generic params");
+        assert!(generic_params.params.is_empty());
+        assert_eq!(generic_params.to_token_stream().to_string(), "< >");
+    }
+
+    let impl_decl = parse_declaration_checked(quote!(impl<> Foo<> {}));
+    let impl_decl = impl_decl.as_impl().unwrap();
+    assert!(impl_decl.impl_generic_params.as_ref().unwrap().params.is_empty());
+}
+
+#[test]
+fn reject_missing_or_mismatched_bodies() {
+    // Same as a truncated `struct Foo(i32`, which cannot be tokenized
+    let truncated_error = parse_item(quote!(struct Foo)).unwrap_err();
+    assert_eq!(
+        truncated_error.to_string(),
+        "cannot parse struct: expected `;`, `( )` or `{ }`, found end of stream"
+    );
+
+    let struct_error = parse_item(quote!(struct Foo[i32])).unwrap_err();
+    assert_eq!(
+        struct_error.to_string(),
+        "cannot parse struct: expected `;`, `( )` or `{ }`, found `[i32]`"
+    );
+
+    let enum_error = parse_item(quote!(enum Foo(A, B))).unwrap_err();
+    assert_eq!(
+        enum_error.to_string(),
+        "cannot parse enum: expected variants in `{ }`, found `(A , B)`"
+    );
+
+    let variant_error = parse_item(quote!(enum Foo { A[i32] })).unwrap_err();
+    assert_eq!(
+        variant_error.to_string(),
+        "cannot parse enum variant `A`: expected `,`, `=`, `( )` or `{ }`, found `[i32]`"
+    );
+
+    let params_error = parse_item(quote!(fn foo[a: i32] {})).unwrap_err();
+    assert_eq!(
+        params_error.to_string(),
+        "cannot parse function: expected parameters in `( )`, found `[a : i32]`"
+    );
+
+    let fn_body_error = parse_item(quote!(fn foo())).unwrap_err();
+    assert_eq!(
+        fn_body_error.to_string(),
+        "cannot parse function: expected `{ }` or `;`, found end of stream"
+    );
+
+    let impl_error = parse_item(quote!(impl Trait for Foo)).unwrap_err();
+    assert_eq!(
+        impl_error.to_string(),
+        "cannot parse impl: expected body in `{ }`, found end of stream"
+    );
+
+    let trait_error = parse_item(quote!(trait Foo: Clone)).unwrap_err();
+    assert_eq!(
+        trait_error.to_string(),
+        "cannot parse trait: expected body in `{ }`, found end of stream"
+    );
+
+    let extern_error = parse_item(quote!(extern "C" [x])).unwrap_err();
+    assert_eq!(
+        extern_error.to_string(),
+        "cannot parse extern block: expected body in `{ }`, found `[x]`"
+    );
+
+    let macro_error = parse_item(quote!(foo!)).unwrap_err();
+    assert_eq!(
+        macro_error.to_string(),
+        "cannot parse macro: expected `{ }` or `( )`, found end of stream"
+    );
+
+    let macro_group_error = parse_item(quote!(foo![x];)).unwrap_err();
+    assert_eq!(
+        macro_group_error.to_string(),
+        "cannot parse macro: expected `{ }` or `( )`, found `[x]`"
+    );
+
+    let where_error = parse_item(quote!(struct Foo where)).unwrap_err();
+    assert_eq!(
+        where_error.to_string(),
+        "cannot parse where clause: expected `{ }`, `;` or `=` after predicates, found end of stream"
+    );
+
+    let type_alias_error = parse_item(quote!(type Foo =)).unwrap_err();
+    assert_eq!(
+        type_alias_error.to_string(),
+        "cannot parse type alias: expected `;`, found end of stream"
+    );
+
+    let const_error = parse_item(quote!(const FOO: u8)).unwrap_err();
+    assert_eq!(
+        const_error.to_string(),
+        "cannot parse const/static: expected `;`, found end of stream"
+    );
+
+    let static_error = parse_item(quote!(static FOO: u8 = 1 2)).unwrap_err();
+    assert_eq!(
+        static_error.to_string(),
+        "cannot parse const/static: expected `;`, found end of stream"
+    );
+}
+
+#[test]
+fn reject_invalid_generic_params() {
+    let prefix_error = parse_item(quote!(struct Foo<T, [U]>;)).unwrap_err();
+    assert_eq!(
+        prefix_error.to_string(),
+        "cannot parse generic params: unexpected token `[U]`"
+    );
+
+    let name_error = parse_item(quote!(fn foo<'a, const 3>() {})).unwrap_err();
+    assert_eq!(
+        name_error.to_string(),
+        "cannot parse generic params: expected identifier, found `3`"
+    );
+
+    let end_error = parse_item(quote!(impl<T)).unwrap_err();
+    assert_eq!(
+        end_error.to_string(),
+        "cannot parse generic params: expected `>`, found end of stream"
+    );
+}
+
 // ==========
 // VISIBILITY
 // ==========
@@ -252,6 +904,59 @@ fn parse_tuple_fields_vis() {
     assert_debug_snapshot!(struct_type);
 }
 
+#[test]
+fn interpret_vis_restriction() {
+    let struct_type = parse_declaration_checked(quote!(
+        pub struct Hello(
+            pub A,
+            pub(crate) B,
+            pub(super) C,
+            pub(self) D,
+            pub(in crate::foo) E,
+            crate F,
+        );
+    ));
+    let fields: Vec<_> = match struct_type.as_struct().unwrap().fields {
+        Fields::Tuple(ref fields) => fields.fields.items().collect(),
+        _ => unreachable!(),
+    };
+    let restriction = |index: usize| fields[index].vis_marker.as_ref().unwrap().restriction();
+
+    assert!(restriction(0).is_none());
+    assert!(matches!(restriction(1), Some(VisRestriction::Crate)));
+    assert!(matches!(restriction(2), Some(VisRestriction::Super)));
+    assert!(matches!(restriction(3), Some(VisRestriction::SelfMod)));
+    let Some(VisRestriction::In(path)) = restriction(4) else {
+        panic!("expected `in` restriction");
+    };
+    assert_eq!(path.to_token_stream().to_string(), "crate :: foo");
+    assert!(matches!(restriction(5), Some(VisRestriction::Crate)));
+}
+
+#[test]
+fn interpret_vis_is_pub() {
+    let struct_type = parse_declaration_checked(quote!(
+        pub struct Hello(pub A, pub(crate) B, crate C);
+    ));
+    let struct_type = struct_type.as_struct().unwrap();
+    let fields: Vec<_> = match struct_type.fields {
+        Fields::Tuple(ref fields) => fields.fields.items().collect(),
+        _ => unreachable!(),
+    };
+
+    assert!(struct_type.vis_marker.as_ref().unwrap().is_pub());
+    assert!(fields[0].vis_marker.as_ref().unwrap().is_pub());
+    assert!(!fields[1].vis_marker.as_ref().unwrap().is_pub());
+    assert!(!fields[2].vis_marker.as_ref().unwrap().is_pub());
+
+    let vis_marker = VisMarker::pub_();
+    assert!(vis_marker.is_pub());
+    assert_eq!(
+        quote!(#vis_marker struct Foo;).to_string(),
+        "pub struct Foo ;"
+    );
+}
+
 // ==========
 // ATTRIBUTES
 // ==========
@@ -355,7 +1060,424 @@ fn parse_complex_attributes() {
     assert_debug_snapshot!(struct_type.attributes()[1].get_single_path_segment());
 }
 
-// =============
+#[test]
+fn interpret_attribute_path() {
+    let struct_type = parse_declaration_checked(quote!(
+        #[derive(Clone)]
+        #[doc = "Hello"]
+        #[path::to::attr(a b c)]
+        #[hello]
+        pub struct Hello;
+    ));
+    let attributes = struct_type.attributes();
+
+    let segment_names = |index: usize| -> Vec<String> {
+        let path = attributes[index].get_path().unwrap();
+        path.segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect()
+    };
+
+    assert_eq!(segment_names(0), ["derive"]);
+    assert_eq!(segment_names(1), ["doc"]);
+    assert_eq!(segment_names(2), ["path", "to", "attr"]);
+    assert_eq!(segment_names(3), ["hello"]);
+
+    assert_eq!(
+        TokenStream::from_iter(attributes[2].get_value_tokens().iter().cloned()).to_string(),
+        "a b c"
+    );
+}
+
+#[test]
+fn interpret_doc_comments() {
+    let struct_type = parse_declaration_checked(quote!(
+        /// First line
+        #[doc = "Tab\tand \"quotes\""]
+        #[doc(hidden)]
+        #[hello = "world"]
+        #[doc = "caf\u{e9} \x41"]
+        pub struct Hello;
+    ));
+    let attributes = struct_type.attributes();
+
+    assert!(attributes[0].is_doc_comment());
+    assert_eq!(attributes[0].doc_string().unwrap(), " First line");
+    assert_eq!(attributes[1].doc_string().unwrap(), "Tab\tand \"quotes\"");
+
+    assert!(!attributes[2].is_doc_comment());
+    assert!(!attributes[3].is_doc_comment());
+    assert_eq!(attributes[3].doc_string(), None);
+    assert_eq!(attributes[4].doc_string().unwrap(), "café A");
+}
+
+#[test]
+fn interpret_field_and_variant_doc_comments() {
+    let struct_type = parse_struct_declaration(quote!(
+        struct Hello {
+            /// First line
+            /// Second line
+            #[serde(default)]
+            /// Third line
+            a: A,
+            b: B,
+        }
+    ));
+    let Fields::Named(fields) = &struct_type.fields else {
+        panic!("expected named fields")
+    };
+    let field = &fields.fields[0].0;
+    let docs: Vec<_> = field
+        .attributes
+        .iter()
+        .map(|attribute| attribute.doc_string())
+        .collect();
+    assert_eq!(
+        docs,
+        [
+            Some(" First line".to_string()),
+            Some(" Second line".to_string()),
+            None,
+            Some(" Third line".to_string()),
+        ]
+    );
+    assert!(fields.fields[1].0.attributes.is_empty());
+
+    let enum_type = parse_declaration_checked(quote!(
+        enum Hello {
+            /// Variant
+            A(
+                /// Tuple field
+                i32,
+            ),
+            B,
+        }
+    ));
+    let variants = &enum_type.as_enum().unwrap().variants;
+    assert_eq!(
+        variants[0].0.attributes[0].doc_string().unwrap(),
+        " Variant"
+    );
+    assert!(variants[1].0.attributes.is_empty());
+
+    let Fields::Tuple(tuple_fields) = &variants[0].0.fields else {
+        panic!("expected tuple fields")
+    };
+    assert_eq!(
+        tuple_fields.fields[0].0.attributes[0].doc_string().unwrap(),
+        " Tuple field"
+    );
+}
+
+#[test]
+fn interpret_inner_attributes() {
+    let mod_decl = parse_declaration_checked(quote!(
+        #[outer]
+        mod hello {
+            #![inner]
+            //! Inner doc
+        }
+    ));
+    let mod_decl = mod_decl.as_module().unwrap();
+
+    assert!(!mod_decl.attributes[0].is_inner());
+    assert!(mod_decl.inner_attributes[0].is_inner());
+    assert!(mod_decl.inner_attributes[1].is_inner());
+    assert!(mod_decl.inner_attributes[1].is_doc_comment());
+}
+
+#[test]
+fn interpret_cfg_attributes() {
+    let struct_type = parse_declaration_checked(quote!(
+        #[cfg(unix)]
+        #[cfg(all(unix, any(target_os = "linux", target_os = "macos"), not(test),))]
+        #[cfg(not(unix, windows))]
+        #[cfg_attr(unix, derive(Clone))]
+        pub struct Hello;
+    ));
+    let attributes = struct_type.attributes();
+
+    let option = attributes[0].as_cfg().unwrap();
+    assert!(matches!(option, CfgPredicate::Option(name) if name == "unix"));
+
+    let CfgPredicate::All(all) = attributes[1].as_cfg().unwrap() else {
+        panic!("expected all(...)");
+    };
+    assert_eq!(all.len(), 3);
+    let CfgPredicate::Any(any) = &all[1] else {
+        panic!("expected any(...), got {:?}", all[1]);
+    };
+    assert!(
+        matches!(&any[1], CfgPredicate::KeyValue(key, value) if key == "target_os" && value == "macos")
+    );
+    assert!(matches!(&all[2], CfgPredicate::Not(_)));
+
+    // `not` takes exactly one predicate
+    assert!(attributes[2].as_cfg().is_none());
+    assert!(attributes[3].as_cfg().is_none());
+}
+
+#[test]
+fn interpret_derive_attributes() {
+    let struct_type = parse_declaration_checked(quote!(
+        #[derive(Clone, ::serde::Serialize, std::fmt::Debug,)]
+        #[derive()]
+        #[derive = "Clone"]
+        #[hello(Clone)]
+        pub struct Hello;
+    ));
+    let attributes = struct_type.attributes();
+
+    let derives = attributes[0].as_derive().unwrap();
+    let derives: Vec<_> = derives
+        .items()
+        .map(|path| path.to_token_stream().to_string())
+        .collect();
+    assert_eq!(
+        derives,
+        ["Clone", ":: serde :: Serialize", "std :: fmt :: Debug"]
+    );
+
+    assert!(attributes[1].as_derive().unwrap().is_empty());
+    assert!(attributes[2].as_derive().is_none());
+    assert!(attributes[3].as_derive().is_none());
+}
+
+#[test]
+fn find_and_strip_attributes() {
+    let struct_type = parse_declaration_checked(quote!(
+        #[derive(Debug)]
+        #[my_macro::config(foo)]
+        struct Hello {
+            #[skip]
+            #[doc = "field"]
+            a: Foo,
+            b: Bar,
+        }
+    ));
+    let struct_type = struct_type.as_struct().unwrap();
+    assert!(struct_type.has_attribute("derive"));
+    assert!(struct_type.has_attribute("my_macro::config"));
+    assert!(!struct_type.has_attribute("config"));
+
+    let remaining = struct_type.without_attribute("my_macro::config");
+    assert_eq!(quote!(#(#remaining)*).to_string(), "# [derive (Debug)]");
+
+    let fields = match &struct_type.fields {
+        Fields::Named(fields) => fields,
+        _ => unreachable!(),
+    };
+    let skipped: Vec<_> = fields
+        .fields
+        .items()
+        .filter(|field| field.has_attribute("skip"))
+        .map(|field| field.name.to_string())
+        .collect();
+    assert_eq!(skipped, ["a"]);
+    assert_eq!(fields.fields[0].0.without_attribute("skip").len(), 1);
+
+    let impl_item = parse_declaration_checked(quote!(
+        #[my_macro::config(foo)]
+        #[allow(unused)]
+        impl Hello {}
+    ));
+    assert!(impl_item.has_attribute("my_macro::config"));
+    let remaining = impl_item.without_attribute("my_macro::config");
+    assert_eq!(quote!(#(#remaining)*).to_string(), "# [allow (unused)]");
+
+    let trait_decl = parse_declaration_checked(quote!(
+        #[my_macro::config]
+        trait Hello {}
+    ));
+    let trait_decl = trait_decl.as_trait().unwrap();
+    assert!(trait_decl.has_attribute("my_macro::config"));
+    assert!(trait_decl.without_attribute("my_macro::config").is_empty());
+}
+
+#[test]
+fn interpret_attribute_literal_values() {
+    let struct_type = parse_declaration_checked(quote!(
+        #[name = "foo"]
+        #[name = r#"raw "foo""#]
+        #[align = 8]
+        #[align = 0x1_0u32]
+        #[align = 1.5]
+        #[name(foo = "bar")]
+        #[name = foo]
+        #[repr(align = 8)]
+        #[value = -3]
+        #[my(name = "foo", align = 8)]
+        #[doc(alias = "foo")]
+        pub struct Hello;
+    ));
+    let attributes = struct_type.attributes();
+
+    assert_eq!(attributes[0].value_string().unwrap(), "foo");
+    assert_eq!(attributes[0].value_int(), None);
+    assert_eq!(attributes[1].value_string().unwrap(), "raw \"foo\"");
+
+    assert_eq!(attributes[2].value_int(), Some(8));
+    assert_eq!(attributes[2].value_string(), None);
+    assert_eq!(attributes[3].value_int(), Some(16));
+    assert_eq!(attributes[4].value_int(), None);
+
+    assert_eq!(attributes[5].value_string().unwrap(), "bar");
+    assert_eq!(attributes[6].value_string(), None);
+
+    assert_eq!(attributes[7].value_int(), Some(8));
+    assert_eq!(attributes[8].value_int(), Some(-3));
+    assert_eq!(attributes[9].value_string(), None);
+    assert_eq!(attributes[9].value_int(), None);
+
+    assert_eq!(attributes[10].value_string().unwrap(), "foo");
+    assert_eq!(attributes[10].doc_string(), None);
+}
+
+#[test]
+fn interpret_repr_attributes() {
+    let struct_type = parse_declaration_checked(quote!(
+        #[repr(C, packed(2))]
+        #[repr(transparent)]
+        #[repr(u8, align(16))]
+        #[repr(packed)]
+        #[repr(simd)]
+        #[derive(C)]
+        pub struct Hello;
+    ));
+    let attributes = struct_type.attributes();
+
+    let hints = attributes[0].as_repr().unwrap().hints;
+    assert!(matches!(
+        hints[..],
+        [ReprHint::C, ReprHint::Packed(Some(2))]
+    ));
+
+    let hints = attributes[1].as_repr().unwrap().hints;
+    assert!(matches!(hints[..], [ReprHint::Transparent]));
+
+    let hints = attributes[2].as_repr().unwrap().hints;
+    assert!(matches!(&hints[..], [ReprHint::Int(int), ReprHint::Align(16)] if int == "u8"));
+
+    let hints = attributes[3].as_repr().unwrap().hints;
+    assert!(matches!(hints[..], [ReprHint::Packed(None)]));
+
+    assert!(attributes[4].as_repr().is_none());
+    assert!(attributes[5].as_repr().is_none());
+}
+
+#[test]
+fn interpret_meta_attributes() {
+    let struct_type = parse_declaration_checked(quote!(
+        #[flag]
+        #[path::to::name = "value"]
+        #[config(a, b = 1 + 2, c(nested(deep), "lit"))]
+        #[empty()]
+        pub struct Hello;
+    ));
+    let attributes = struct_type.attributes();
+
+    let meta = attributes[0].parse_meta().unwrap();
+    assert!(matches!(&meta, Meta::Path(path) if path.segments.len() == 1));
+
+    let meta = attributes[1].parse_meta().unwrap();
+    let Meta::NameValue { path, value, .. } = meta else {
+        panic!("expected name-value, found {:?}", meta)
+    };
+    assert_eq!(path.segments.len(), 3);
+    assert_eq!(value.to_token_stream().to_string(), "\"value\"");
+
+    let meta = attributes[2].parse_meta().unwrap();
+    assert_debug_snapshot!(meta);
+    similar_asserts::assert_eq!(
+        meta.to_token_stream().to_string(),
+        quote!(config(a, b = 1 + 2, c(nested(deep), "lit"))).to_string()
+    );
+
+    let Meta::List(list) = attributes[3].parse_meta().unwrap() else {
+        panic!("expected list")
+    };
+    assert!(list.items.is_empty());
+
+    let error = parse_declaration_checked(quote!(
+        #[config(a b)]
+        struct Hello;
+    ))
+    .attributes()[0]
+        .parse_meta()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse attribute arguments: expected path, found `a b`"
+    );
+}
+
+#[test]
+fn query_mixed_meta_list() {
+    #[rustfmt::skip] // would remove trailing comma
+    let struct_type = parse_declaration_checked(quote!(
+        #[serde(rename = "x", default, with(module), rename = "y",)]
+        pub struct Hello;
+    ));
+
+    let Meta::List(list) = struct_type.attributes()[0].parse_meta().unwrap() else {
+        panic!("expected list")
+    };
+    assert_eq!(list.items.len(), 4);
+    assert!(list.items.has_trailing_punct());
+
+    let renames = list.get("rename");
+    assert_eq!(renames.len(), 2);
+    assert_eq!(
+        renames[0].value().unwrap().to_token_stream().to_string(),
+        "\"x\""
+    );
+    assert_eq!(
+        renames[1].value().unwrap().to_token_stream().to_string(),
+        "\"y\""
+    );
+
+    assert!(matches!(list.get("default")[..], [Meta::Path(_)]));
+    assert!(matches!(list.get("with")[..], [Meta::List(_)]));
+    assert!(list.get("skip").is_empty());
+    assert!(list.get("with")[0].value().is_none());
+}
+
+#[test]
+fn interpreted_attributes_to_tokens() {
+    let struct_type = parse_declaration_checked(quote!(
+        #[cfg(all(unix, not(feature = "std")))]
+        #[repr(C, packed(2), u8)]
+        pub(in crate::foo) struct Hello;
+    ));
+    let attributes = struct_type.attributes();
+
+    let cfg = attributes[0].as_cfg().unwrap();
+    assert_eq!(
+        cfg.to_token_stream().to_string(),
+        quote!(all(unix, not(feature = "std"))).to_string()
+    );
+
+    let repr = attributes[1].as_repr().unwrap();
+    assert_eq!(
+        repr.to_token_stream().to_string(),
+        quote!(C, packed(2), u8).to_string()
+    );
+
+    let vis_marker = struct_type
+        .as_struct()
+        .unwrap()
+        .vis_marker
+        .as_ref()
+        .unwrap();
+    let restriction = vis_marker.restriction().unwrap();
+    assert_eq!(
+        restriction.to_token_stream().to_string(),
+        quote!(in crate::foo).to_string()
+    );
+}
+
+// =============
 // WHERE CLAUSES
 // =============
 
@@ -531,6 +1653,232 @@ fn parse_enum_empty_generic_params() {
     assert_debug_snapshot!(enum_type);
 }
 
+#[test]
+fn parse_generic_param_defaults() {
+    let struct_type = parse_declaration_checked(quote!(
+        struct Hello<X, Y: Clone = Vec<u8>, Z = HashMap<K, V>>(X, Y, Z);
+    ));
+
+    assert_debug_snapshot!(struct_type);
+}
+
+#[test]
+fn parse_const_generic_param_defaults() {
+    let struct_type = parse_declaration_checked(quote!(
+        struct Matrix<const N: usize = 3, const M: i32 = { -1 }, T = [u8; 4]>([[T; N]; M]);
+    ));
+
+    assert_debug_snapshot!(struct_type);
+}
+
+#[test]
+fn parse_const_generic_param_ty() {
+    let struct_type = parse_struct_declaration(quote!(
+        struct Hello<'a, T: Clone, const N: usize>(&'a [T; N]);
+    ));
+
+    let params: Vec<_> = struct_type
+        .generic_params
+        .as_ref()
+        .unwrap()
+        .params
+        .items()
+        .collect();
+    assert_eq!(params[0].kind(), GenericParamKind::Lifetime);
+    assert!(params[0].const_ty().is_none());
+    assert_eq!(params[1].kind(), GenericParamKind::Type);
+    assert!(params[1].const_ty().is_none());
+
+    assert!(params[2].is_const());
+    assert_eq!(params[2].kind(), GenericParamKind::Const);
+    assert!(params[2].bound.is_none());
+    assert_eq!(
+        params[2].const_ty().unwrap().to_token_stream().to_string(),
+        "usize"
+    );
+}
+
+// ==============
+// GENERIC BOUNDS
+// ==============
+
+#[test]
+fn parse_generic_bounds() {
+    let struct_type = parse_declaration_checked(quote!(
+        struct Hello<T: Clone + Send, U: Fn(A) -> B + Iterator<Item = (C, D)>>(T, U);
+    ));
+
+    let bounds: Vec<_> = struct_type
+        .generic_params()
+        .unwrap()
+        .params
+        .items()
+        .map(|param| param.bound.as_ref().unwrap().bounds())
+        .collect();
+
+    assert_debug_snapshot!(bounds);
+}
+
+#[test]
+fn parse_lifetime_bounds() {
+    let struct_type = parse_struct_declaration(quote!(
+        struct Hello<'a, 'b, T: 'a + Clone + 'static, U: 'b, V: Send>(&'a T, &'b U, V);
+    ));
+
+    let bounds: Vec<_> = struct_type
+        .get_type_params()
+        .map(|param| param.bound.as_ref().unwrap())
+        .map(|bound| {
+            let lifetimes: Vec<_> = bound
+                .lifetime_bounds()
+                .iter()
+                .map(|lifetime| lifetime.name.to_string())
+                .collect();
+            let traits: Vec<_> = bound
+                .trait_bounds()
+                .iter()
+                .map(|ty| quote!(#ty).to_string())
+                .collect();
+            (lifetimes, traits)
+        })
+        .collect();
+
+    assert_eq!(
+        bounds,
+        [
+            (
+                vec!["a".to_string(), "static".to_string()],
+                vec!["Clone".to_string()]
+            ),
+            (vec!["b".to_string()], vec![]),
+            (vec![], vec!["Send".to_string()]),
+        ]
+    );
+}
+
+#[test]
+fn parse_maybe_bounds() {
+    let struct_type = parse_struct_declaration(quote!(
+        struct Hello<T: ?Sized + Send>(Box<T>);
+    ));
+
+    let param = struct_type.get_type_params().next().unwrap();
+    let bound = param.bound.as_ref().unwrap();
+
+    let maybe_bounds = bound.maybe_bounds();
+    let trait_bounds = bound.trait_bounds();
+    assert_eq!(maybe_bounds.len(), 1);
+    assert_eq!(maybe_bounds[0].to_token_stream().to_string(), "Sized");
+    assert_eq!(trait_bounds.len(), 1);
+    assert_eq!(trait_bounds[0].to_token_stream().to_string(), "Send");
+}
+
+#[test]
+fn parse_negative_bounds() {
+    let impl_decl = parse_declaration_checked(quote!(
+        impl<T: !Send + Clone + ?Sized> Foo for Bar<T> {}
+    ));
+    let impl_decl = impl_decl.as_impl().unwrap();
+    let param = &impl_decl.impl_generic_params.as_ref().unwrap().params[0].0;
+    let bound = param.bound.as_ref().unwrap();
+
+    let negative_bounds = bound.negative_bounds();
+    assert_eq!(negative_bounds.len(), 1);
+    assert_eq!(negative_bounds[0].to_token_stream().to_string(), "Send");
+
+    let trait_bounds = bound.trait_bounds();
+    assert_eq!(trait_bounds.len(), 1);
+    assert_eq!(trait_bounds[0].to_token_stream().to_string(), "Clone");
+    assert_eq!(bound.maybe_bounds().len(), 1);
+}
+
+#[test]
+#[rustfmt::skip] // would remove trailing `+`
+fn parse_trailing_plus_bounds() {
+    let function = parse_declaration_checked(quote!(
+        fn foo<T: Send +, U: Clone + + Sync>() where T: 'static + {}
+    ));
+
+    let params: Vec<_> = function.generic_params().unwrap().params.items().collect();
+    let send_bounds = params[0].bound.as_ref().unwrap().bounds();
+    assert_eq!(send_bounds.len(), 1);
+    assert!(send_bounds.has_trailing_punct());
+    assert_eq!(send_bounds.to_token_stream().to_string(), "Send +");
+
+    let clone_bounds = params[1].bound.as_ref().unwrap().bounds();
+    let clone_bounds: Vec<_> = clone_bounds.items().map(|ty| ty.to_token_stream().to_string()).collect();
+    assert_eq!(clone_bounds, ["Clone", "Sync"]);
+
+    let where_clause = function.as_function().unwrap().where_clause.as_ref().unwrap();
+    let predicate = &where_clause.items[0].0;
+    assert_eq!(predicate.bound.lifetime_bounds().len(), 1);
+    assert!(predicate.bound.trait_bounds().is_empty());
+}
+
+#[test]
+fn parse_where_clause_hrtb() {
+    let func = parse_declaration_checked(quote!(
+        fn apply<F, G>(f: F, g: G)
+        where
+            for<'a, 'b> F: Fn(&'a u8, &'b u8) -> &'a u8,
+            G: for<'c> Fn(&'c u8) -> Box<dyn for<'d> Fn(&'d u8)>,
+        {
+        }
+    ));
+    let func = func.as_function().unwrap();
+    let predicates: Vec<_> = func.where_clause.as_ref().unwrap().items.items().collect();
+    assert_eq!(predicates.len(), 2);
+
+    let lifetimes = predicates[0].for_lifetimes();
+    let lifetimes: Vec<_> = lifetimes.iter().map(|lt| lt.name.to_string()).collect();
+    assert_eq!(lifetimes, ["a", "b"]);
+    assert_eq!(
+        predicates[0].bound.to_token_stream().to_string(),
+        quote!(: Fn(&'a u8, &'b u8) -> &'a u8).to_string()
+    );
+
+    assert!(predicates[1].for_lifetimes().is_empty());
+    assert_eq!(
+        predicates[1].bound.to_token_stream().to_string(),
+        quote!(: for<'c> Fn(&'c u8) -> Box<dyn for<'d> Fn(&'d u8)>).to_string()
+    );
+}
+
+#[test]
+fn parse_where_clause_lifetime_outlives() {
+    let struct_decl = parse_declaration_checked(quote!(
+        struct Hello<'a, 'b, 'c, T>(&'a &'b &'c T)
+        where
+            'a: 'b + 'c,
+            T: 'a;
+    ));
+    let predicates: Vec<_> = struct_decl
+        .as_struct()
+        .unwrap()
+        .where_clause
+        .as_ref()
+        .unwrap()
+        .items
+        .items()
+        .collect();
+
+    let (lifetime, bounds) = predicates[0].as_lifetime_outlives().unwrap();
+    let bounds: Vec<_> = bounds.iter().map(|lt| lt.name.to_string()).collect();
+    assert_eq!(lifetime.name.to_string(), "a");
+    assert_eq!(bounds, ["b", "c"]);
+
+    assert!(predicates[1].as_lifetime_outlives().is_none());
+}
+
+#[test]
+fn interpret_where_clause_left_ty() {
+    let predicate = WhereClausePredicate::parse(quote!(Vec<T>: Clone));
+
+    let path = predicate.left_ty().as_path().unwrap();
+    assert_eq!(path.segments[0].ident.to_string(), "Vec");
+    assert!(path.segments[0].generic_args.is_some());
+}
+
 // ============
 // GENERIC ARGS
 // ============
@@ -553,6 +1901,112 @@ fn parse_generic_args_turbofish() {
     assert_debug_snapshot!(generic_args);
 }
 
+#[test]
+fn parse_generic_args_lifetimes() {
+    for (ty, expected_lifetimes) in [
+        (quote!(Foo<'a>), vec!["a"]),
+        (quote!(Foo<'a, T>), vec!["a"]),
+        (quote!(Foo<'static>), vec!["static"]),
+        (quote!(Foo<'a, 'b, T>), vec!["a", "b"]),
+    ] {
+        let ty = TypeExpr {
+            tokens: ty.into_iter().collect(),
+        };
+        let path = ty.as_path().unwrap();
+        let args = path.segments[0].generic_args.as_ref().unwrap();
+        let lifetimes: Vec<_> = args
+            .args
+            .items()
+            .filter_map(|arg| match arg {
+                GenericArg::Lifetime { lifetime } => Some(lifetime.name.to_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(lifetimes, expected_lifetimes);
+    }
+
+    let mut tokens = quote!(<'a T>).into_iter().peekable();
+    let error = crate::parse_type::parse_generic_args(&mut tokens, None).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse lifetime generic argument: expected `,` or `>`, found `T`"
+    );
+}
+
+#[test]
+fn parse_generic_args_assoc_bound() {
+    let generic_args = parse_generic_args_checked(quote!(
+        <Item: Clone + Send, Other = path::Type, path::to::Type>
+    ));
+
+    let args: Vec<_> = generic_args.args.items().collect();
+    match args[0] {
+        GenericArg::AssocBound { ident, bound } => {
+            assert_eq!(ident, "Item");
+            let bounds: Vec<_> = bound
+                .bounds()
+                .items()
+                .map(|b| b.to_token_stream().to_string())
+                .collect();
+            assert_eq!(bounds, ["Clone", "Send"]);
+        }
+        other => panic!("expected associated type bound, got {:?}", other),
+    }
+    assert!(matches!(args[1], GenericArg::Binding { .. }));
+    assert!(matches!(args[2], GenericArg::TypeOrConst { .. }));
+}
+
+#[test]
+fn generic_args_const_expr() {
+    let generic_args = parse_generic_args_checked(quote!(
+        <{ N + 1 }, 3, -1, true, "str", T, N, Item = 3, 'a>
+    ));
+
+    let const_args: Vec<_> = generic_args
+        .args
+        .items()
+        .map(|arg| arg.as_const_expr().map(|expr| expr.to_string()))
+        .collect();
+    assert_eq!(
+        const_args,
+        [
+            Some("{ N + 1 }".to_string()),
+            Some("3".to_string()),
+            Some("- 1".to_string()),
+            Some("true".to_string()),
+            Some("\"str\"".to_string()),
+            None,
+            None,
+            None,
+            None,
+        ]
+    );
+}
+
+#[test]
+fn generic_args_helpers() {
+    let generic_args = parse_generic_args_checked(quote!(
+        <'a, path::to::Type, Item = i32, Other: Clone, 15>
+    ));
+    assert_eq!(generic_args.len(), 5);
+    assert!(!generic_args.is_empty());
+    assert!(matches!(
+        generic_args.iter().next(),
+        Some(GenericArg::Lifetime { .. })
+    ));
+
+    let types: Vec<_> = generic_args
+        .types()
+        .map(|ty| ty.to_token_stream().to_string())
+        .collect();
+    assert_eq!(types, ["path :: to :: Type", "15"]);
+
+    let empty_args = parse_generic_args_checked(quote!(::<>));
+    assert_eq!(empty_args.len(), 0);
+    assert!(empty_args.is_empty());
+    assert_eq!(empty_args.types().count(), 0);
+}
+
 #[test]
 fn parse_inline_generic_args() {
     let struct_decl = parse_declaration_checked(quote!(
@@ -598,17 +2052,210 @@ fn parse_complex_enum_variant() {
     assert_debug_snapshot!(enum_type_3);
 }
 
+#[test]
+fn parse_enum_variant_computed_value() {
+    let enum_type = parse_declaration_checked(quote!(
+        enum Flags {
+            A = 1 << 0,
+            B = 1 << 1,
+            AB = A | B,
+            C = 4,
+        }
+    ));
+    let enum_type = enum_type.as_enum().unwrap();
+
+    let values: Vec<_> = enum_type
+        .variants
+        .items()
+        .map(|variant| variant.value.as_ref().unwrap().value_tokens().to_string())
+        .collect();
+    assert_eq!(values, ["1 << 0", "1 << 1", "A | B", "4"]);
+}
+
+#[test]
+fn parse_enum_variant_turbofish_value() {
+    let enum_type = parse_declaration_checked(quote!(
+        enum Hello {
+            A = foo::<u8, u16>(),
+            B = bar::<Vec<u8>, fn() -> u8>(1, 2),
+            C = (1 < 2) as isize,
+            D = <u8 as Tr<A, B>>::X,
+            E = X as <T as Tr<A, B>>::Y,
+            F = 1 << 3,
+            G,
+        }
+    ));
+    let enum_type = enum_type.as_enum().unwrap();
+
+    let values: Vec<_> = enum_type
+        .variants
+        .items()
+        .map(|variant| {
+            variant
+                .value
+                .as_ref()
+                .map(|value| value.value_tokens().to_string())
+        })
+        .collect();
+    assert_eq!(
+        values,
+        [
+            Some(quote!(foo::<u8, u16>()).to_string()),
+            Some(quote!(bar::<Vec<u8>, fn() -> u8>(1, 2)).to_string()),
+            Some(quote!((1 < 2) as isize).to_string()),
+            Some(quote!(<u8 as Tr<A, B>>::X).to_string()),
+            Some(quote!(X as <T as Tr<A, B>>::Y).to_string()),
+            Some(quote!(1 << 3).to_string()),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn enum_c_like_and_discriminants() {
+    let enum_type = parse_declaration_checked(quote!(
+        enum Hello {
+            A,
+            B = 2,
+        }
+    ));
+    let enum_type = enum_type.as_enum().unwrap();
+    assert!(enum_type.is_c_enum());
+    assert!(enum_type.has_discriminants());
+
+    let enum_type = parse_declaration_checked(quote!(
+        enum Hello {
+            A,
+            B(Foo),
+        }
+    ));
+    let enum_type = enum_type.as_enum().unwrap();
+    assert!(!enum_type.is_c_enum());
+    assert!(!enum_type.has_discriminants());
+
+    let enum_type = parse_declaration_checked(quote!(
+        enum Empty {}
+    ));
+    let enum_type = enum_type.as_enum().unwrap();
+    assert!(enum_type.is_c_enum());
+    assert!(!enum_type.has_discriminants());
+}
+
+#[test]
+fn enum_inferred_discriminants() {
+    let enum_type = parse_declaration_checked(quote!(
+        enum Hello {
+            A,
+            B = 0x10,
+            C,
+            D = -3i32,
+            E,
+            F = 1_000,
+        }
+    ));
+    let enum_type = enum_type.as_enum().unwrap();
+
+    let explicit: Vec<_> = enum_type
+        .explicit_discriminants()
+        .iter()
+        .map(|value| value.map(|value| value.value_tokens().to_string()))
+        .collect();
+    assert_eq!(
+        explicit,
+        [
+            None,
+            Some("0x10".into()),
+            None,
+            Some("- 3i32".into()),
+            None,
+            Some("1_000".into())
+        ]
+    );
+    assert_eq!(
+        enum_type.inferred_discriminants(),
+        Some(vec![0, 16, 17, -3, -2, 1000])
+    );
+
+    let enum_type = parse_declaration_checked(quote!(
+        enum Hello {
+            A = 1,
+            B = FOO,
+            C,
+        }
+    ));
+    assert_eq!(enum_type.as_enum().unwrap().inferred_discriminants(), None);
+
+    let enum_type = parse_declaration_checked(quote!(
+        enum Hello {
+            A = -9223372036854775808,
+            B = -9223372036854775809,
+        }
+    ));
+    let values: Vec<_> = enum_type
+        .as_enum()
+        .unwrap()
+        .explicit_discriminants()
+        .iter()
+        .map(|value| value.unwrap().value_int())
+        .collect();
+    assert_eq!(values, [Some(i64::MIN), None]);
+}
+
 // Macros in enum item position are illegal in Rust.
 #[test]
-#[should_panic]
 fn parse_enum_with_macro() {
-    let _ = parse_item(quote!(
+    let enum_error = parse_item(quote!(
         enum Hello {
             A = 1,
             macroified! { B = 2 },
             macroified!(B; 2),
         }
+    ))
+    .unwrap_err();
+    assert_eq!(
+        enum_error.to_string(),
+        "cannot parse enum variant `macroified`: expected `,`, `=`, `( )` or `{ }`, found `!`"
+    );
+}
+
+#[test]
+fn parse_raw_identifiers() {
+    let struct_type = parse_declaration_checked(quote!(
+        struct r#S<r#T> {
+            r#type: r#T,
+            r#fn: u32,
+        }
+    ));
+    assert_eq!(struct_type.name().unwrap().to_string(), "r#S");
+    let Fields::Named(fields) = &struct_type.as_struct().unwrap().fields else {
+        panic!("expected named fields")
+    };
+    let names: Vec<String> = fields
+        .fields
+        .items()
+        .map(|field| field.name.to_string())
+        .collect();
+    assert_eq!(names, ["r#type", "r#fn"]);
+
+    let enum_type = parse_declaration_checked(quote!(
+        enum E {
+            r#async,
+            r#match(u8),
+        }
+    ));
+    let variants: Vec<String> = enum_type
+        .as_enum()
+        .unwrap()
+        .variants
+        .items()
+        .map(|variant| variant.name.to_string())
+        .collect();
+    assert_eq!(variants, ["r#async", "r#match"]);
+
+    let function = parse_declaration_checked(quote!(
+        fn r#impl(r#in: u8) -> r#T {}
     ));
+    assert_eq!(function.name().unwrap().to_string(), "r#impl");
 }
 
 // =================
@@ -954,6 +2601,56 @@ fn parse_fn_self_param() {
     assert_debug_snapshot!(func_ref_mut_self);
 }
 
+#[test]
+fn parse_fn_body_group() {
+    let func = parse_declaration_checked(quote!(
+        fn foobar(x: i32) -> i32 {
+            let y = x * 2;
+            y + 1
+        }
+    ));
+    let func = func.as_function().unwrap();
+
+    let body = func.body.as_ref().unwrap();
+    assert_eq!(body.delimiter(), Delimiter::Brace);
+    assert_eq!(
+        body.stream().to_string(),
+        quote!(let y = x * 2; y + 1).to_string()
+    );
+    assert!(func.tk_semicolon.is_none());
+
+    let signature = parse_declaration_checked(quote!(
+        fn foobar(x: i32) -> i32;
+    ));
+    let signature = signature.as_function().unwrap();
+
+    assert!(signature.body.is_none());
+    assert!(signature.tk_semicolon.is_some());
+}
+
+#[test]
+fn parse_fn_typed_self_param() {
+    let func = parse_declaration_checked(quote!(
+        fn foobar(self: Box<Self>, mut self: Pin<&mut Self>, x: i32) {}
+    ));
+    let func = func.as_function().unwrap();
+
+    let params: Vec<_> = func.params.iter().map(|(param, _)| param).collect();
+    let FnParam::Typed(boxed) = params[0] else {
+        panic!("expected typed param, got {:?}", params[0]);
+    };
+    assert_eq!(boxed.name.to_string(), "self");
+    assert_eq!(boxed.ty.to_token_stream().to_string(), "Box < Self >");
+
+    let FnParam::Typed(pinned) = params[1] else {
+        panic!("expected typed param, got {:?}", params[1]);
+    };
+    assert!(pinned.tk_mut.is_some());
+    assert_eq!(pinned.name.to_string(), "self");
+
+    assert!(matches!(params[2], FnParam::Typed(_)));
+}
+
 // ============
 // TYPE EDITING
 // ============
@@ -1066,6 +2763,32 @@ fn add_bounded_ty_param() {
     assert_quote_snapshot!(basic_type);
 }
 
+#[test]
+#[rustfmt::skip] // would remove trailing `+`
+fn add_trait_bound_to_type_params() {
+    let struct_type = parse_struct_declaration(quote!(
+        struct Hello<'a: 'static, T, U: Clone, V: Send +, W:, const N: usize = 3>(&'a T, U, V, W);
+    ));
+
+    let mut params = struct_type.generic_params.unwrap();
+    params.add_trait_bound(TypeExpr {
+        tokens: quote!(my::Trait<'a>).into_iter().collect(),
+    });
+
+    similar_asserts::assert_eq!(
+        params.to_token_stream().to_string(),
+        quote!(<'a: 'static, T: my::Trait<'a>, U: Clone + my::Trait<'a>, V: Send + my::Trait<'a>, W: my::Trait<'a>, const N: usize = 3>).to_string()
+    );
+
+    let trait_bounds: Vec<_> = params
+        .params
+        .items()
+        .filter_map(|param| param.bound.as_ref())
+        .map(|bound| bound.trait_bounds().len())
+        .collect();
+    assert_eq!(trait_bounds, [0, 1, 2, 2, 1]);
+}
+
 #[test]
 fn add_const_param() {
     let basic_type = parse_struct_declaration(quote!(
@@ -1122,6 +2845,320 @@ fn add_where_item() {
     assert_quote_snapshot!(type_with_args);
 }
 
+#[test]
+fn merge_where_clauses() {
+    let function = parse_function_where_clause(quote!(
+        fn foo<A, B>()
+        where
+            A: Clone,
+        {
+        }
+    ));
+    let other = parse_function_where_clause(quote!(
+        fn foo<A, B>()
+        where
+            B: Send,
+            A: 'static,
+        {
+        }
+    ));
+
+    let mut merged = function.clone();
+    merged.merge(other.clone());
+    assert_eq!(merged.items.len(), 3);
+    similar_asserts::assert_eq!(
+        merged.to_token_stream().to_string(),
+        quote!(where A: Clone, B: Send, A: 'static,).to_string()
+    );
+
+    merged.push_predicate(
+        TypeExpr {
+            tokens: quote!(Vec<B>).into_iter().collect(),
+        },
+        quote!(Debug),
+    );
+    similar_asserts::assert_eq!(
+        merged.to_token_stream().to_string(),
+        quote!(where A: Clone, B: Send, A: 'static, Vec<B>: Debug).to_string()
+    );
+
+    let mut empty = WhereClause::default();
+    assert!(empty.is_empty());
+    empty.merge(other);
+    assert!(!empty.is_empty());
+    similar_asserts::assert_eq!(
+        empty.to_token_stream().to_string(),
+        quote!(where B: Send, A: 'static,).to_string()
+    );
+
+    let mut unchanged = function.clone();
+    unchanged.merge(WhereClause::default());
+    assert_eq!(unchanged.items.len(), 1);
+    similar_asserts::assert_eq!(
+        unchanged.to_token_stream().to_string(),
+        quote!(where A: Clone,).to_string()
+    );
+}
+
+fn parse_function_where_clause(tokens: TokenStream) -> WhereClause {
+    let function = parse_declaration_checked(tokens);
+    function
+        .as_function()
+        .unwrap()
+        .where_clause
+        .clone()
+        .unwrap()
+}
+
+#[test]
+fn edit_punctuated_items() {
+    let mut struct_type = parse_struct_declaration(quote!(
+        struct Hello(A, B);
+    ));
+    let Fields::Tuple(fields) = &mut struct_type.fields else {
+        unreachable!();
+    };
+
+    for field in fields.fields.items_mut() {
+        field.ty = TypeExpr {
+            tokens: quote!(Option<i32>).into_iter().collect(),
+        };
+    }
+
+    assert_eq!(
+        quote!(#struct_type).to_string(),
+        quote!(
+            struct Hello(Option<i32>, Option<i32>);
+        )
+        .to_string()
+    );
+}
+
+#[test]
+fn iterate_punctuated() {
+    let struct_type = parse_struct_declaration(quote!(
+        struct Hello {
+            a: A,
+            b: B,
+        }
+    ));
+    let Fields::Named(fields) = &struct_type.fields else {
+        unreachable!();
+    };
+
+    let mut names = vec![];
+    for field in &fields.fields {
+        names.push(field.name.to_string());
+    }
+    assert_eq!(names, ["a", "b"]);
+
+    let commas: Vec<_> = fields
+        .fields
+        .pairs()
+        .map(|(_, punct)| punct.is_some())
+        .collect();
+    assert_eq!(commas, [true, true]);
+
+    let generic_params = parse_struct_declaration(quote!(
+        struct Hello<A, B>;
+    ))
+    .generic_params
+    .unwrap();
+    let pairs: Vec<_> = generic_params
+        .params
+        .into_pairs()
+        .map(|(param, punct)| (param.name.to_string(), punct.is_some()))
+        .collect();
+    assert_eq!(pairs, [("A".to_string(), true), ("B".to_string(), false)]);
+
+    let names: Vec<_> = fields
+        .fields
+        .clone()
+        .into_iter()
+        .map(|field| field.name.to_string())
+        .collect();
+    assert_eq!(names, ["a", "b"]);
+}
+
+#[test]
+fn transform_punctuated() {
+    let struct_type = parse_struct_declaration(quote!(
+        struct Hello {
+            a: A,
+            #[skip]
+            b: B,
+            c: C,
+        }
+    ));
+    let Fields::Named(fields) = struct_type.fields else {
+        unreachable!();
+    };
+
+    let names = fields.fields.clone().map(|field| field.name);
+    assert_eq!(quote!(#names).to_string(), "a , b , c ,");
+
+    let initializers = fields.fields.filter_map(|field| {
+        let name = field.name;
+        match field.attributes.is_empty() {
+            true => Some(quote!(#name: Default::default())),
+            false => None,
+        }
+    });
+    assert_eq!(
+        quote!(#initializers).to_string(),
+        quote!(a: Default::default(), c: Default::default(),).to_string()
+    );
+
+    let generic_params = parse_struct_declaration(quote!(
+        struct Hello<A, B>;
+    ))
+    .generic_params
+    .unwrap();
+    let names = generic_params
+        .params
+        .filter_map(|param| (param.name != "B").then_some(param.name));
+    assert_eq!(quote!(#names).to_string(), "A");
+}
+
+#[test]
+fn collect_punctuated() {
+    let tys = [quote!(i32), quote!(Vec<u8>)];
+    let mut args: Punctuated<TypeExpr> = tys
+        .into_iter()
+        .map(|ty| TypeExpr {
+            tokens: ty.into_iter().collect(),
+        })
+        .collect();
+    assert_eq!(quote!(#args).to_string(), quote!(i32, Vec<u8>).to_string());
+
+    args.extend([TypeExpr {
+        tokens: quote!(bool).into_iter().collect(),
+    }]);
+    assert_eq!(
+        quote!(#args).to_string(),
+        quote!(i32, Vec<u8>, bool).to_string()
+    );
+
+    args.push_value(TypeExpr {
+        tokens: quote!(char).into_iter().collect(),
+    });
+    assert_eq!(
+        quote!(#args).to_string(),
+        quote!(i32, Vec<u8>, bool, char).to_string()
+    );
+
+    let empty: Punctuated<TypeExpr> = std::iter::empty().collect();
+    assert!(empty.is_empty());
+    assert_eq!(quote!(#empty).to_string(), "");
+}
+
+#[test]
+fn punctuated_trailing_punct() {
+    // Parsed from strings, because rustfmt normalizes trailing commas in `quote!`
+    let fields_of = |code: &str| match parse_struct_declaration(code.parse().unwrap()).fields {
+        Fields::Named(fields) => fields.fields,
+        _ => unreachable!(),
+    };
+
+    assert!(fields_of("struct Hello { a: A, b: B, }").has_trailing_punct());
+    assert!(!fields_of("struct Hello { a: A, b: B }").has_trailing_punct());
+    assert!(!fields_of("struct Hello {}").has_trailing_punct());
+
+    let mut collected: Punctuated<TypeExpr> = [quote!(A)]
+        .into_iter()
+        .map(|ty| TypeExpr {
+            tokens: ty.into_iter().collect(),
+        })
+        .collect();
+    assert!(!collected.has_trailing_punct());
+
+    collected.push(
+        TypeExpr {
+            tokens: quote!(B).into_iter().collect(),
+        },
+        Some(Punct::new(',', Spacing::Alone)),
+    );
+    assert!(collected.has_trailing_punct());
+    assert_eq!(quote!(#collected).to_string(), "A , B ,");
+}
+
+#[test]
+fn retain_punctuated() {
+    let retain_fields = |tokens: TokenStream, skipped: &[&str]| {
+        let mut struct_type = parse_struct_declaration(tokens);
+        let Fields::Named(fields) = &mut struct_type.fields else {
+            unreachable!();
+        };
+        fields
+            .fields
+            .retain(|field| !skipped.contains(&field.name.to_string().as_str()));
+        quote!(#struct_type).to_string()
+    };
+
+    let without_comma = quote!(
+        struct Hello {
+            a: A,
+            b: B,
+            c: C,
+        }
+    );
+    let with_comma = quote!(
+        struct Hello {
+            a: A,
+            b: B,
+            c: C,
+        }
+    );
+
+    assert_eq!(
+        retain_fields(without_comma.clone(), &["a"]),
+        quote!(
+            struct Hello {
+                b: B,
+                c: C,
+            }
+        )
+        .to_string()
+    );
+    assert_eq!(
+        retain_fields(without_comma.clone(), &["b"]),
+        quote!(
+            struct Hello {
+                a: A,
+                c: C,
+            }
+        )
+        .to_string()
+    );
+    assert_eq!(
+        retain_fields(without_comma.clone(), &["c"]),
+        quote!(
+            struct Hello {
+                a: A,
+                b: B,
+            }
+        )
+        .to_string()
+    );
+    assert_eq!(
+        retain_fields(with_comma.clone(), &["c"]),
+        quote!(
+            struct Hello {
+                a: A,
+                b: B,
+            }
+        )
+        .to_string()
+    );
+    assert_eq!(
+        retain_fields(without_comma, &["a", "b", "c"]),
+        quote!(
+            struct Hello {}
+        )
+        .to_string()
+    );
+}
+
 // =================
 // IMPL DECLARATIONS
 // =================
@@ -1211,8 +3248,29 @@ fn parse_impl_trait_generic() {
         }
     );
 
-    let impl_decl = parse_declaration_checked(expr);
-    assert_debug_snapshot!(impl_decl);
+    let impl_decl = parse_declaration_checked(expr);
+    assert_debug_snapshot!(impl_decl);
+}
+
+#[test]
+fn parse_impl_negative_and_unsafe() {
+    let negative_impl = parse_declaration_checked(quote! {
+        impl<T> !Send for MyType<T> {}
+    });
+    let negative_impl = negative_impl.as_impl().unwrap();
+    assert!(negative_impl.tk_bang.is_some());
+    assert_eq!(negative_impl.trait_ty.to_token_stream().to_string(), "Send");
+    assert_eq!(
+        negative_impl.self_ty.to_token_stream().to_string(),
+        "MyType < T >"
+    );
+
+    let unsafe_impl = parse_declaration_checked(quote! {
+        unsafe impl Sync for MyType {}
+    });
+    let unsafe_impl = unsafe_impl.as_impl().unwrap();
+    assert!(unsafe_impl.tk_unsafe.is_some());
+    assert!(unsafe_impl.tk_bang.is_none());
 }
 
 // =================
@@ -1240,6 +3298,33 @@ fn parse_type_complex() {
     assert_debug_snapshot!(ty_decl);
 }
 
+#[test]
+fn parse_type_generic() {
+    let expr = quote!(
+        type MyType<'a, T: Clone>
+        where
+            T: Iterator<Item = u8>,
+        = std::borrow::Cow<'a, [T]>;
+    );
+
+    let ty_decl = parse_declaration_checked(expr);
+    assert_debug_snapshot!(ty_decl);
+}
+
+#[test]
+fn parse_type_trailing_where_clause() {
+    let expr = quote!(
+        type Item<'a>
+            = &'a T
+        where
+            Self: 'a,
+            T: 'a;
+    );
+
+    let ty_decl = parse_declaration_checked(expr);
+    assert_debug_snapshot!(ty_decl);
+}
+
 // =====================
 // CONSTANT DECLARATIONS
 // =====================
@@ -1265,6 +3350,17 @@ fn parse_constant_complex() {
     assert_debug_snapshot!(const_decl);
 }
 
+#[test]
+fn parse_static_mut() {
+    let expr = quote!(
+        #[no_mangle]
+        pub static mut COUNTER: std::sync::atomic::AtomicU32 = AtomicU32::new(0);
+    );
+
+    let static_decl = parse_declaration_checked(expr);
+    assert_debug_snapshot!(static_decl);
+}
+
 // Syntactically valid in venial
 #[test]
 fn parse_constant_expressionless() {
@@ -1316,6 +3412,44 @@ fn interpret_ty_expr_invalid_as_path() {
     assert!(invalid.is_none())
 }
 
+#[test]
+fn interpret_ty_expr_self_as_path() {
+    let impl_decl = parse_declaration_checked(quote!(
+        impl Iterator for Foo {
+            fn next(&mut self) -> Option<Self::Item> {}
+            fn by_ref(&mut self) -> Self {}
+        }
+    ));
+    let return_types: Vec<_> = impl_decl
+        .as_impl()
+        .unwrap()
+        .body_items
+        .iter()
+        .filter_map(|member| match member {
+            ImplMember::AssocFunction(function) => function.return_ty.clone(),
+            _ => None,
+        })
+        .collect();
+
+    let option_ty = &return_types[0];
+    assert!(!option_ty.is_self_ty());
+    let inner_ty = option_ty.as_wrapper("Option").unwrap();
+    assert!(!inner_ty.is_self_ty());
+    let assoc_path = inner_ty.as_path().expect("as_path()");
+    let segments: Vec<String> = assoc_path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    assert_eq!(segments, ["Self", "Item"]);
+
+    let self_ty = &return_types[1];
+    assert!(self_ty.is_self_ty());
+    let self_path = self_ty.as_path().expect("as_path()");
+    assert_eq!(self_path.segments.len(), 1);
+    assert_eq!(self_path.segments[0].ident.to_string(), "Self");
+}
+
 #[test]
 fn interpret_ty_expr_from_declarative_macro() {
     // Simulates a declarative macro which takes a `ty` placeholder generates an item with a proc-macro attribute.
@@ -1341,6 +3475,354 @@ fn interpret_ty_expr_from_declarative_macro() {
     assert_debug_snapshot!(path);
 }
 
+// ================
+// TYPE EXPRESSIONS
+// ================
+
+#[test]
+fn interpret_ty_expr_as_reference() {
+    let tokens = quote!(&'a mut Vec<i32>);
+    let ty_expr = TypeExpr {
+        tokens: tokens.clone().into_iter().collect(),
+    };
+
+    let reference = ty_expr.as_reference().expect("as_reference()");
+    assert_eq!(reference.lifetime.as_ref().unwrap().name.to_string(), "a");
+    assert!(reference.is_mut());
+    assert_eq!(reference.inner.to_token_stream().to_string(), "Vec < i32 >");
+    similar_asserts::assert_eq!(quote!(#reference).to_string(), tokens.to_string());
+
+    let ty_expr = TypeExpr {
+        tokens: quote!(&str).into_iter().collect(),
+    };
+    let reference = ty_expr.as_reference().expect("as_reference()");
+    assert!(reference.lifetime.is_none());
+    assert!(!reference.is_mut());
+    assert_eq!(reference.inner.to_token_stream().to_string(), "str");
+
+    let ty_expr = TypeExpr {
+        tokens: quote!(Box<&str>).into_iter().collect(),
+    };
+    assert!(ty_expr.as_reference().is_none());
+}
+
+#[test]
+fn interpret_ty_expr_as_tuple() {
+    let ty_expr = TypeExpr {
+        tokens: quote!((A, Vec<B, C>, (D, E))).into_iter().collect(),
+    };
+    let elements = ty_expr.as_tuple().expect("as_tuple()");
+    let elements: Vec<_> = elements
+        .items()
+        .map(|ty| ty.to_token_stream().to_string())
+        .collect();
+    assert_eq!(elements, ["A", "Vec < B , C >", "(D , E)"]);
+
+    let unit = TypeExpr {
+        tokens: quote!(()).into_iter().collect(),
+    };
+    assert_eq!(unit.as_tuple().expect("as_tuple()").len(), 0);
+
+    let single = TypeExpr {
+        tokens: quote!((A,)).into_iter().collect(),
+    };
+    assert_eq!(single.as_tuple().expect("as_tuple()").len(), 1);
+
+    let parenthesized = TypeExpr {
+        tokens: quote!((A)).into_iter().collect(),
+    };
+    assert!(parenthesized.as_tuple().is_none());
+}
+
+#[test]
+fn interpret_ty_expr_as_slice_and_array() {
+    let slice = TypeExpr {
+        tokens: quote!([Vec<T>]).into_iter().collect(),
+    };
+    let element_ty = slice.as_slice().expect("as_slice()");
+    assert_eq!(element_ty.to_token_stream().to_string(), "Vec < T >");
+    assert!(slice.as_array().is_none());
+
+    let array = TypeExpr {
+        tokens: quote!([[u8; 2]; N + 1]).into_iter().collect(),
+    };
+    let (element_ty, len) = array.as_array().expect("as_array()");
+    assert_eq!(element_ty.to_token_stream().to_string(), "[u8 ; 2]");
+    assert_eq!(len.to_token_stream().to_string(), "N + 1");
+    assert!(array.as_slice().is_none());
+
+    let tuple = TypeExpr {
+        tokens: quote!((u8, u16)).into_iter().collect(),
+    };
+    assert!(tuple.as_slice().is_none());
+    assert!(tuple.as_array().is_none());
+}
+
+#[test]
+fn interpret_ty_expr_as_wrapper() {
+    let option = TypeExpr {
+        tokens: quote!(core::option::Option<Vec<u8>>).into_iter().collect(),
+    };
+    let inner = option.as_wrapper("Option").expect("as_wrapper()");
+    assert_eq!(inner.to_token_stream().to_string(), "Vec < u8 >");
+    assert!(option.as_wrapper("Vec").is_none());
+
+    let map = TypeExpr {
+        tokens: quote!(HashMap<K, V>).into_iter().collect(),
+    };
+    assert!(map.as_wrapper("HashMap").is_none());
+
+    let cow = TypeExpr {
+        tokens: quote!(Cow<'a>).into_iter().collect(),
+    };
+    assert!(cow.as_wrapper("Cow").is_none());
+}
+
+#[test]
+fn interpret_ty_expr_as_dyn_and_impl() {
+    let dyn_ty = TypeExpr {
+        tokens: quote!(dyn Iterator<Item = (A, B)> + Send + 'static)
+            .into_iter()
+            .collect(),
+    };
+    let bounds: Vec<_> = dyn_ty
+        .as_dyn()
+        .expect("as_dyn()")
+        .items()
+        .map(|bound| bound.to_token_stream().to_string())
+        .collect();
+    assert_eq!(bounds, ["Iterator < Item = (A , B) >", "Send", "'static"]);
+    assert!(dyn_ty.as_impl().is_none());
+
+    let impl_ty = TypeExpr {
+        tokens: quote!(impl FnOnce() -> u8).into_iter().collect(),
+    };
+    let bounds = impl_ty.as_impl().expect("as_impl()");
+    assert_eq!(bounds.len(), 1);
+    assert!(impl_ty.as_dyn().is_none());
+
+    let concrete = TypeExpr {
+        tokens: quote!(Box<dyn Trait>).into_iter().collect(),
+    };
+    assert!(concrete.as_dyn().is_none());
+    assert!(concrete.as_impl().is_none());
+}
+
+#[test]
+fn interpret_ty_expr_unit_and_never() {
+    let function = parse_item(quote!(
+        fn foo(a: (), b: !, c: (u8,), d: Never) -> ();
+    ))
+    .unwrap();
+    let function = function.as_function().unwrap();
+
+    let types: Vec<_> = function
+        .params
+        .items()
+        .map(|param| match param {
+            FnParam::Typed(param) => &param.ty,
+            FnParam::Receiver(_) => unreachable!(),
+        })
+        .collect();
+    assert!(types[0].is_unit() && !types[0].is_never());
+    assert!(types[1].is_never() && !types[1].is_unit());
+    assert!(!types[2].is_unit());
+    assert!(!types[3].is_never());
+    assert!(function.return_ty.as_ref().unwrap().is_unit());
+}
+
+#[test]
+fn interpret_ty_expr_as_pointer() {
+    let const_ptr = TypeExpr {
+        tokens: quote!(*const *mut u8).into_iter().collect(),
+    };
+    let pointer = const_ptr.as_pointer().expect("as_pointer()");
+    assert!(!pointer.is_mut());
+    assert_eq!(pointer.inner.to_token_stream().to_string(), "* mut u8");
+    assert!(pointer.inner.as_pointer().expect("as_pointer()").is_mut());
+    similar_asserts::assert_eq!(
+        quote!(#pointer).to_string(),
+        const_ptr.to_token_stream().to_string()
+    );
+
+    let reference = TypeExpr {
+        tokens: quote!(&*const u8).into_iter().collect(),
+    };
+    assert!(reference.as_pointer().is_none());
+}
+
+#[test]
+fn interpret_ty_expr_as_fn_pointer() {
+    let simple = TypeExpr {
+        tokens: quote!(fn(A, Vec<B, C>) -> C).into_iter().collect(),
+    };
+    let fn_pointer = simple.as_fn_pointer().expect("as_fn_pointer()");
+    let param_types: Vec<_> = fn_pointer
+        .params
+        .items()
+        .map(|param| param.ty.to_token_stream().to_string())
+        .collect();
+    assert_eq!(param_types, ["A", "Vec < B , C >"]);
+    assert!(fn_pointer.tk_unsafe.is_none() && fn_pointer.tk_extern.is_none());
+    assert_eq!(
+        fn_pointer
+            .return_ty
+            .as_ref()
+            .unwrap()
+            .to_token_stream()
+            .to_string(),
+        "C"
+    );
+    similar_asserts::assert_eq!(
+        quote!(#fn_pointer).to_string(),
+        simple.to_token_stream().to_string()
+    );
+
+    let ffi = TypeExpr {
+        tokens: quote!(unsafe extern "C" fn(fmt: *const u8, ...))
+            .into_iter()
+            .collect(),
+    };
+    let fn_pointer = ffi.as_fn_pointer().expect("as_fn_pointer()");
+    assert!(fn_pointer.tk_unsafe.is_some());
+    assert_eq!(fn_pointer.extern_abi.as_ref().unwrap().to_string(), "\"C\"");
+    assert_eq!(fn_pointer.params.len(), 1);
+    assert_eq!(fn_pointer.params[0].0.name.as_ref().unwrap(), "fmt");
+    assert!(fn_pointer.tk_variadic.is_some());
+    assert!(fn_pointer.return_ty.is_none());
+    similar_asserts::assert_eq!(
+        quote!(#fn_pointer).to_string(),
+        ffi.to_token_stream().to_string()
+    );
+
+    let higher_ranked = TypeExpr {
+        tokens: quote!(for<'a> fn(&'a u8) -> &'a u8).into_iter().collect(),
+    };
+    let fn_pointer = higher_ranked.as_fn_pointer().expect("as_fn_pointer()");
+    assert_eq!(fn_pointer.for_lifetimes.as_ref().unwrap().params.len(), 1);
+    similar_asserts::assert_eq!(
+        quote!(#fn_pointer).to_string(),
+        higher_ranked.to_token_stream().to_string()
+    );
+
+    for not_fn_pointer in [quote!(Fn(u8) -> u8), quote!(&fn()), quote!(fn)] {
+        let ty = TypeExpr {
+            tokens: not_fn_pointer.into_iter().collect(),
+        };
+        assert!(ty.as_fn_pointer().is_none());
+    }
+}
+
+#[test]
+fn interpret_ty_expr_as_qualified_path() {
+    let ty = TypeExpr {
+        tokens: quote!(<Foo as Bar>::Baz<X>).into_iter().collect(),
+    };
+    let qualified_path = ty.as_qualified_path().expect("as_qualified_path()");
+    assert_eq!(qualified_path.self_ty.to_token_stream().to_string(), "Foo");
+    assert_eq!(
+        qualified_path
+            .trait_path
+            .as_ref()
+            .unwrap()
+            .to_token_stream()
+            .to_string(),
+        "Bar"
+    );
+    let last_segment = qualified_path.path.segments.last().unwrap();
+    assert_eq!(last_segment.ident, "Baz");
+    assert_eq!(last_segment.generic_args.as_ref().unwrap().args.len(), 1);
+    similar_asserts::assert_eq!(
+        quote!(#qualified_path).to_string(),
+        ty.to_token_stream().to_string()
+    );
+    assert!(ty.as_path().is_none());
+
+    let ty = TypeExpr {
+        tokens: quote!(<Vec<fn() -> u8> as std::iter::IntoIterator<Item = u8>>::IntoIter::Item)
+            .into_iter()
+            .collect(),
+    };
+    let qualified_path = ty.as_qualified_path().expect("as_qualified_path()");
+    assert_eq!(qualified_path.path.segments.len(), 2);
+    assert_eq!(
+        qualified_path.trait_path.as_ref().unwrap().segments.len(),
+        3
+    );
+    similar_asserts::assert_eq!(
+        quote!(#qualified_path).to_string(),
+        ty.to_token_stream().to_string()
+    );
+
+    let ty = TypeExpr {
+        tokens: quote!(<T>::Assoc).into_iter().collect(),
+    };
+    let qualified_path = ty.as_qualified_path().expect("as_qualified_path()");
+    assert!(qualified_path.tk_as.is_none() && qualified_path.trait_path.is_none());
+
+    for not_qualified in [
+        quote!(Foo::Bar),
+        quote!(<T as Trait>),
+        quote!(<T as Trait>Foo),
+    ] {
+        let ty = TypeExpr {
+            tokens: not_qualified.into_iter().collect(),
+        };
+        assert!(ty.as_qualified_path().is_none());
+    }
+}
+
+#[test]
+fn parse_standalone_type() {
+    let ty = parse_type(quote!(std::collections::HashMap<K, Vec<V>>)).unwrap();
+    assert_eq!(
+        ty.to_token_stream().to_string(),
+        quote!(std::collections::HashMap<K, Vec<V>>).to_string()
+    );
+    let path = ty.as_path().unwrap();
+    assert_eq!(path.segments.len(), 3);
+
+    let ty = parse_type(quote!(&'a [u8])).unwrap();
+    assert!(ty.as_reference().unwrap().inner.as_slice().is_some());
+
+    let ty = parse_type(quote!(fn(A, B) -> C)).unwrap();
+    assert!(ty.as_fn_pointer().is_some());
+
+    assert_eq!(
+        parse_type(quote!(Foo, Bar)).unwrap_err().to_string(),
+        "cannot parse type: unexpected trailing token `,`"
+    );
+    assert!(parse_type(quote!()).is_err());
+}
+
+#[test]
+fn parse_standalone_path() {
+    let path = parse_path(quote!(::std::collections::HashMap<K, V>)).unwrap();
+    assert_eq!(path.segments.len(), 3);
+    assert!(path.segments[0].tk_separator_colons.is_some());
+    assert_eq!(
+        path.segments[2].generic_args.as_ref().unwrap().args.len(),
+        2
+    );
+    assert_eq!(
+        path.to_token_stream().to_string(),
+        quote!(::std::collections::HashMap<K, V>).to_string()
+    );
+
+    let path = parse_path(quote!(crate::helpers::foo)).unwrap();
+    assert_eq!(path.segments[0].ident, "crate");
+
+    assert_eq!(
+        parse_path(quote!(&Foo)).unwrap_err().to_string(),
+        "cannot parse path: expected path, found `& Foo`"
+    );
+    assert!(parse_path(quote!(foo::bar baz)).is_err());
+    assert!(parse_path(quote!()).is_err());
+    assert!(parse_path(quote!(a::<)).is_err());
+    assert!(parse_path(quote!(a < b)).is_err());
+    assert!(parse_path(quote!(a::<b, c)).is_err());
+}
+
 // ================
 // MOD DECLARATIONS
 // ================
@@ -1402,6 +3884,113 @@ fn parse_mod() {
     assert_debug_snapshot!(mod_decl);
 }
 
+#[test]
+fn parse_macro_rules() {
+    let macro_decl = parse_declaration_checked(quote! {
+        #[macro_export]
+        macro_rules! square {
+            ($x:expr) => { $x * $x };
+        }
+    });
+    let macro_decl = macro_decl.as_macro().unwrap();
+
+    assert_eq!(macro_decl.name.to_string(), "macro_rules");
+    assert_eq!(
+        macro_decl.tk_declared_name.as_ref().unwrap().to_string(),
+        "square"
+    );
+    assert_eq!(macro_decl.attributes.len(), 1);
+    assert!(macro_decl.tk_semicolon.is_none());
+
+    let rules = macro_decl
+        .inner_tokens
+        .iter()
+        .cloned()
+        .collect::<TokenStream>();
+    assert_eq!(
+        rules.to_string(),
+        quote!(($x:expr) => { $x * $x };).to_string()
+    );
+}
+
+// ================
+// USE DECLARATIONS
+// ================
+
+#[test]
+fn parse_use_simple() {
+    let use_decl = parse_declaration_checked(quote!(
+        pub use ::std::mem;
+    ));
+    let use_decl = use_decl.as_use_declaration().unwrap();
+
+    assert!(use_decl.tk_leading_colons.is_some());
+    let UseTree::Path(path) = &use_decl.tree else {
+        panic!("expected path, got {:?}", use_decl.tree);
+    };
+    assert_eq!(path.ident.to_string(), "std");
+    assert!(matches!(&*path.tree, UseTree::Name(name) if name == "mem"));
+
+    let import_tree = use_decl.import_tree();
+    assert_eq!(quote!(#import_tree).to_string(), ":: std :: mem");
+}
+
+#[test]
+fn parse_use_tree() {
+    let use_decl = parse_declaration_checked(quote!(
+        use std::{
+            self,
+            collections::{HashMap as Map, *},
+            io::Write as _,
+        };
+    ));
+    let use_decl = use_decl.as_use_declaration().unwrap();
+
+    let UseTree::Path(std_path) = &use_decl.tree else {
+        panic!("expected path, got {:?}", use_decl.tree);
+    };
+    let UseTree::Group(group) = &*std_path.tree else {
+        panic!("expected group, got {:?}", std_path.tree);
+    };
+    assert_eq!(group.items.len(), 3);
+    assert!(matches!(&group.items[0].0, UseTree::Name(name) if name == "self"));
+
+    let UseTree::Path(collections_path) = &group.items[1].0 else {
+        panic!("expected path, got {:?}", group.items[1].0);
+    };
+    let UseTree::Group(collections_group) = &*collections_path.tree else {
+        panic!("expected group, got {:?}", collections_path.tree);
+    };
+    assert!(matches!(
+        &collections_group.items[0].0,
+        UseTree::Rename(rename) if rename.ident == "HashMap" && rename.rename == "Map"
+    ));
+    assert!(matches!(&collections_group.items[1].0, UseTree::Glob(_)));
+
+    let UseTree::Path(io_path) = &group.items[2].0 else {
+        panic!("expected path, got {:?}", group.items[2].0);
+    };
+    assert!(matches!(
+        &*io_path.tree,
+        UseTree::Rename(rename) if rename.rename == "_"
+    ));
+}
+
+#[test]
+fn reject_invalid_use() {
+    let error = parse_item(quote!(use std::{a b};)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse use declaration: expected `,`, found `b`"
+    );
+
+    let error = parse_item(quote!(use std::collections::;)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse use declaration: expected identifier, `*` or `{`, found `;`"
+    );
+}
+
 // ==================
 // TRAIT DECLARATIONS
 // ==================
@@ -1447,6 +4036,69 @@ fn parse_trait_decorated() {
     assert_debug_snapshot!(trait_decl);
 }
 
+#[test]
+fn parse_auto_trait() {
+    let auto_trait = parse_declaration_checked(quote! {
+        pub auto trait Marker {}
+    });
+    let auto_trait = auto_trait.as_trait().unwrap();
+    assert!(auto_trait.tk_auto.is_some());
+    assert!(auto_trait.tk_unsafe.is_none());
+
+    let unsafe_auto_trait = parse_declaration_checked(quote! {
+        unsafe auto trait Marker {}
+    });
+    let unsafe_auto_trait = unsafe_auto_trait.as_trait().unwrap();
+    assert!(unsafe_auto_trait.tk_auto.is_some());
+    assert!(unsafe_auto_trait.tk_unsafe.is_some());
+
+    let auto_macro = parse_declaration_checked(quote! {
+        auto! { foo }
+    });
+    let auto_macro = auto_macro.as_macro().unwrap();
+    assert_eq!(auto_macro.name.to_string(), "auto");
+}
+
+#[test]
+fn parse_trait_assoc_types() {
+    let trait_decl = parse_declaration_checked(quote! {
+        trait Container {
+            type Item: Clone + 'static = Vec<u8>;
+            type Iter<'a>: Iterator<Item = &'a Self::Item> where Self: 'a;
+            type Plain;
+        }
+    });
+    let trait_decl = trait_decl.as_trait().unwrap();
+    let assoc_types: Vec<_> = trait_decl
+        .body_items
+        .iter()
+        .map(|item| match item {
+            TraitMember::AssocType(assoc_ty) => assoc_ty,
+            _ => panic!("expected associated type, got {:?}", item),
+        })
+        .collect();
+
+    let item = assoc_types[0];
+    assert_eq!(item.bound.as_ref().unwrap().bounds().len(), 2);
+    assert_eq!(
+        item.initializer_ty.to_token_stream().to_string(),
+        "Vec < u8 >"
+    );
+
+    let iter = assoc_types[1];
+    assert!(iter.generic_params.is_some());
+    assert_eq!(
+        iter.bound.as_ref().unwrap().to_token_stream().to_string(),
+        quote!(: Iterator<Item = &'a Self::Item>).to_string()
+    );
+    assert!(iter.where_clause.is_some());
+    assert!(iter.initializer_ty.is_none());
+
+    let plain = assoc_types[2];
+    assert!(plain.bound.is_none());
+    assert!(plain.initializer_ty.is_none());
+}
+
 // ====================
 // EXTERN CRATE + BLOCK
 // ====================
@@ -1475,6 +4127,36 @@ fn parse_extern_block() {
     assert_debug_snapshot!(unsafe_extern);
 }
 
+#[test]
+fn parse_extern_block_items() {
+    let extern_decl = parse_declaration_checked(quote! {
+        unsafe extern "C" {
+            fn strlen(s: *const c_char) -> usize;
+            static errno: c_int;
+        }
+    });
+    let extern_decl = extern_decl.as_extern_block().unwrap();
+
+    assert!(extern_decl.tk_unsafe.is_some());
+    assert_eq!(
+        extern_decl.extern_abi.as_ref().unwrap().to_string(),
+        "\"C\""
+    );
+
+    let ImplMember::AssocFunction(function) = &extern_decl.body_items[0] else {
+        panic!("expected function, got {:?}", extern_decl.body_items[0]);
+    };
+    assert_eq!(function.name.to_string(), "strlen");
+    assert!(function.body.is_none());
+    assert!(function.tk_semicolon.is_some());
+
+    let ImplMember::AssocConstant(static_decl) = &extern_decl.body_items[1] else {
+        panic!("expected static, got {:?}", extern_decl.body_items[1]);
+    };
+    assert_eq!(static_decl.name.to_string(), "errno");
+    assert_eq!(static_decl.tk_const_or_static.to_string(), "static");
+}
+
 #[test]
 fn parse_extern_crate() {
     let simple_crate = quote! {
@@ -1496,3 +4178,147 @@ fn parse_extern_crate() {
     let as_underscore_crate = parse_declaration_checked(as_underscore_crate);
     assert_debug_snapshot!(as_underscore_crate);
 }
+
+#[test]
+fn reject_extern_crate_in_impl() {
+    let impl_error = parse_item(quote! {
+        impl Foo {
+            extern crate std;
+        }
+    })
+    .unwrap_err();
+    assert_eq!(
+        impl_error.to_string(),
+        "`extern crate` is not allowed inside impl, trait or extern blocks"
+    );
+}
+
+// ================
+// ATTRIBUTE MACROS
+// ================
+
+#[test]
+fn parse_attribute_macro_args() {
+    let (args, item) = parse_attribute_macro(
+        quote!(
+            skip,
+            rename = "Bar",
+            with(crate::helpers, default),
+            "literal"
+        ),
+        quote!(
+            struct Foo;
+        ),
+    )
+    .unwrap();
+
+    assert_eq!(item.name().unwrap().to_string(), "Foo");
+    assert_eq!(args.len(), 4);
+    assert_debug_snapshot!(args);
+
+    let nested = match &args[2].0 {
+        Meta::List(list) => list,
+        other => panic!("expected list, found {:?}", other),
+    };
+    assert_eq!(nested.path.to_token_stream().to_string(), "with");
+    assert_eq!(nested.items.len(), 2);
+
+    similar_asserts::assert_eq!(
+        args.to_token_stream().to_string(),
+        quote!(
+            skip,
+            rename = "Bar",
+            with(crate::helpers, default),
+            "literal"
+        )
+        .to_string()
+    );
+}
+
+#[test]
+fn parse_attribute_macro_empty_args() {
+    let (args, _item) = parse_attribute_macro(
+        quote!(),
+        quote!(
+            fn foo() {}
+        ),
+    )
+    .unwrap();
+    assert!(args.is_empty());
+}
+
+#[test]
+fn reject_malformed_attribute_macro_args() {
+    let missing_comma = parse_attribute_macro(
+        quote!(a b),
+        quote!(
+            struct Foo;
+        ),
+    )
+    .unwrap_err();
+    assert_eq!(
+        missing_comma.to_string(),
+        "cannot parse attribute arguments: expected path, found `a b`"
+    );
+
+    let missing_value = parse_attribute_macro(
+        quote!(a =, b),
+        quote!(
+            struct Foo;
+        ),
+    )
+    .unwrap_err();
+    assert_eq!(
+        missing_value.to_string(),
+        "cannot parse attribute arguments: expected value after `=`"
+    );
+
+    let list_then_path = parse_attribute_macro(
+        quote!(a(b) c),
+        quote!(
+            struct Foo;
+        ),
+    )
+    .unwrap_err();
+    assert_eq!(
+        list_then_path.to_string(),
+        "cannot parse attribute arguments: expected `,`, found `c`"
+    );
+}
+
+// ====================
+// SYN INTEROPERABILITY
+// ====================
+
+#[cfg(feature = "syn")]
+#[test]
+fn convert_to_and_from_syn() {
+    let struct_type = parse_struct_declaration(quote!(
+        #[derive(Debug)]
+        pub struct Hello<T> {
+            a: T,
+        }
+    ));
+
+    let syn_struct = syn::ItemStruct::try_from(struct_type.clone()).unwrap();
+    assert_eq!(syn_struct.ident, "Hello");
+    assert_eq!(syn_struct.fields.len(), 1);
+
+    let roundtrip = Struct::try_from(syn_struct).unwrap();
+    similar_asserts::assert_eq!(
+        roundtrip.to_token_stream().to_string(),
+        struct_type.to_token_stream().to_string()
+    );
+
+    let syn_item: syn::Item = syn::parse_quote!(
+        enum World {
+            A,
+            B,
+        }
+    );
+    let item = Item::try_from(syn_item).unwrap();
+    assert_eq!(item.as_enum().unwrap().variants.len(), 2);
+
+    let syn_item = syn::Item::try_from(item).unwrap();
+    assert!(matches!(syn_item, syn::Item::Enum(_)));
+}
@@ -67,6 +67,9 @@ pub enum Item {
 
     /// Extern crate declaration.
     ExternCrate(ExternCrate),
+
+    /// Item of an unsupported kind, only returned by [`parse_item_lenient()`](crate::parse_item_lenient).
+    Unknown(UnknownItem),
 }
 
 /// Declaration of a struct.
@@ -227,6 +230,8 @@ pub struct Trait {
     pub attributes: Vec<Attribute>,
     pub vis_marker: Option<VisMarker>,
     pub tk_unsafe: Option<Ident>,
+    /// `auto` keyword of auto traits such as `Send`.
+    pub tk_auto: Option<Ident>,
     pub tk_trait: Ident,
     pub name: Ident,
     pub generic_params: Option<GenericParamList>,
@@ -247,6 +252,8 @@ pub enum TraitMember {
     AssocConstant(Constant),
     AssocType(TypeAlias),
     Macro(Macro),
+    /// Member of an unsupported kind, only returned when parsing leniently.
+    Unknown(UnknownItem),
 }
 
 /// Declaration of an `impl` block.
@@ -268,12 +275,19 @@ pub enum TraitMember {
 ///     // ...
 /// }
 /// ```
+/// or:
+/// ```no_run
+/// # #[cfg(FALSE)]
+/// impl !Send for MyType {}
+/// ```
 #[derive(Clone, Debug)]
 pub struct Impl {
     pub attributes: Vec<Attribute>,
     pub tk_unsafe: Option<Ident>,
     pub tk_impl: Ident,
     pub impl_generic_params: Option<GenericParamList>,
+    /// `!` of a negative impl such as `impl !Send for MyType {}`.
+    pub tk_bang: Option<Punct>,
     pub trait_ty: Option<TypeExpr>,
     pub tk_for: Option<Ident>,
     pub self_ty: TypeExpr,
@@ -293,6 +307,8 @@ pub enum ImplMember {
     AssocConstant(Constant),
     AssocType(TypeAlias),
     Macro(Macro),
+    /// Member of an unsupported kind, only returned when parsing leniently.
+    Unknown(UnknownItem),
 }
 
 /// Constant or static declaration.
@@ -344,6 +360,7 @@ impl Constant {
 ///
 /// ```no_run
 /// type MyType = i32;
+/// type MyGenericType<T> where T: Clone = Vec<T>;
 /// ```
 #[derive(Clone, Debug)]
 pub struct TypeAlias {
@@ -351,9 +368,14 @@ pub struct TypeAlias {
     pub vis_marker: Option<VisMarker>,
     pub tk_type: Ident,
     pub name: Ident,
+    pub generic_params: Option<GenericParamList>,
     pub bound: Option<GenericBound>,
+    /// Where clause before the `=`, e.g. `type Item<T> where T: Clone = Vec<T>;`.
+    pub where_clause: Option<WhereClause>,
     pub tk_equals: Option<Punct>,
     pub initializer_ty: Option<TypeExpr>,
+    /// Where clause after the type, e.g. `type Item<T> = Vec<T> where T: Clone;` (as used for associated types).
+    pub trailing_where_clause: Option<WhereClause>,
     pub tk_semicolon: Punct,
 }
 
@@ -392,11 +414,17 @@ pub struct Function {
 /// always in that order.
 #[derive(Clone, Debug, Default)]
 pub struct FnQualifiers {
+    /// `default` keyword, only valid in specializing impls.
     pub tk_default: Option<Ident>,
+    /// `const` keyword.
     pub tk_const: Option<Ident>,
+    /// `async` keyword.
     pub tk_async: Option<Ident>,
+    /// `unsafe` keyword.
     pub tk_unsafe: Option<Ident>,
+    /// `extern` keyword.
     pub tk_extern: Option<Ident>,
+    /// ABI string after `extern`, e.g. `"C"`. Only present if `extern` is present.
     pub extern_abi: Option<Literal>,
 }
 
@@ -418,10 +446,9 @@ pub enum FnParam {
 
 /// [`Function`] parameter which refers to `self` in some way.
 ///
-/// Possible parameters captures by this are `self`, `mut self`, `&self` or `&mut self`.
-/// Reference lifetimes are not yet supported.
+/// Possible parameters captures by this are `self`, `mut self`, `&self`, `&mut self` or `&'a self`.
 ///
-/// Parameters of the form `self: Pin<&mut Self>` are recognized as [`FnTypedParam`].
+/// Parameters of the form `self: Pin<&mut Self>` are recognized as [`FnTypedParam`] with name `self`.
 #[derive(Clone, Debug)]
 pub struct FnReceiverParam {
     pub attributes: Vec<Attribute>,
@@ -520,6 +547,100 @@ pub enum AttributeValue {
     Empty,
 }
 
+/// A configuration predicate, as found in `#[cfg(...)]`.
+///
+/// **Example input:**
+///
+/// ```no_run
+/// # #[cfg(FALSE)]
+/// #[cfg(all(unix, not(feature = "std")))]
+/// # struct Foo;
+/// ```
+///
+/// See also: <https://doc.rust-lang.org/reference/conditional-compilation.html>
+#[derive(Clone, Debug)]
+pub enum CfgPredicate {
+    /// Example: `unix`
+    Option(Ident),
+    /// Example: `feature = "std"`. The value is unescaped.
+    KeyValue(Ident, String),
+    /// Example: `all(unix, test)`
+    All(Vec<CfgPredicate>),
+    /// Example: `any(unix, windows)`
+    Any(Vec<CfgPredicate>),
+    /// Example: `not(test)`
+    Not(Box<CfgPredicate>),
+}
+
+/// The layout hints of a `#[repr(...)]` attribute.
+///
+/// **Example input:**
+///
+/// ```no_run
+/// #[repr(C, packed(2))]
+/// # struct Foo;
+/// ```
+///
+/// When converted to tokens, only the hints are emitted, e.g. `C, packed(2)`.
+///
+/// See also: <https://doc.rust-lang.org/reference/type-layout.html#representations>
+#[derive(Clone, Debug)]
+pub struct Repr {
+    /// The comma-separated hints, in declaration order.
+    pub hints: Vec<ReprHint>,
+}
+
+/// A single hint inside a `#[repr(...)]` attribute.
+#[derive(Clone, Debug)]
+pub enum ReprHint {
+    /// `C`
+    C,
+    /// `transparent`
+    Transparent,
+    /// `packed` or `packed(n)`
+    Packed(Option<u64>),
+    /// `align(n)`
+    Align(u64),
+    /// Primitive integer such as `u8` or `isize`, for enums.
+    Int(Ident),
+}
+
+/// An argument of an attribute or attribute macro, e.g. `skip`, `rename = "x"` or `serde(default)`.
+///
+/// See [`Attribute::parse_meta()`] and [`parse_attribute_macro()`](crate::parse_attribute_macro).
+///
+/// **Example input:**
+///
+/// ```no_run
+/// # #[cfg(FALSE)]
+/// #[my_attribute(skip, rename = "x", with(module::path), "literal")]
+/// # struct Foo;
+/// ```
+#[derive(Clone, Debug)]
+pub enum Meta {
+    /// E.g. `skip` or `path::to::flag`.
+    Path(Path),
+    /// E.g. `with(module::path)`.
+    List(MetaList),
+    /// E.g. `rename = "x"`.
+    NameValue {
+        path: Path,
+        tk_equals: Punct,
+        /// Expression after the `=`, usually a single literal.
+        value: ValueExpr,
+    },
+    /// A literal without name, e.g. `"literal"` or `8` in `align(8)`.
+    Lit(Literal),
+}
+
+/// A nested list of [`Meta`] items, such as `with(module::path)`.
+#[derive(Clone, Debug)]
+pub struct MetaList {
+    pub path: Path,
+    pub tk_parens: GroupSpan,
+    pub items: Punctuated<Meta>,
+}
+
 /// Visibility marker, eg `pub`, `pub(crate)`, `pub(super)`, etc.
 #[derive(Clone)]
 pub struct VisMarker {
@@ -529,6 +650,21 @@ pub struct VisMarker {
     pub tk_token2: Option<TokenTree>,
 }
 
+/// The restriction of a [`VisMarker`], e.g. `crate` in `pub(crate)`.
+///
+/// See also: <https://doc.rust-lang.org/reference/visibility-and-privacy.html>
+#[derive(Clone, Debug)]
+pub enum VisRestriction {
+    /// `pub(crate)`
+    Crate,
+    /// `pub(super)`
+    Super,
+    /// `pub(self)`
+    SelfMod,
+    /// `pub(in path::to::module)`
+    In(Path),
+}
+
 /// The generic parameters declared right after your type's name.
 ///
 /// **Example input:**
@@ -557,7 +693,40 @@ pub struct GenericParam {
     /// Either `'` for lifetimes, `const` for const parameters, or None for type parameters.
     pub tk_prefix: Option<TokenTree>,
     pub name: Ident,
+    /// The `:` token and type of a const parameter, e.g. `usize` in `const N: usize`.
+    pub const_ty: Option<(Punct, TypeExpr)>,
+    /// Bounds of a lifetime or type parameter; always None for const parameters.
     pub bound: Option<GenericBound>,
+    /// The `=` token and default value, e.g. `u32` in `T = u32` or `3` in `const N: usize = 3`.
+    pub default: Option<(Punct, GenericParamDefault)>,
+}
+
+/// The kind of a generic parameter, as returned by [`GenericParam::kind()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GenericParamKind {
+    /// E.g. `'a`.
+    Lifetime,
+    /// E.g. `T: Clone`.
+    Type,
+    /// E.g. `const N: usize`.
+    Const,
+}
+
+/// Default value of a generic parameter.
+///
+/// **Example input:**
+///
+/// ```no_run
+/// # struct MyStruct<
+/// T = u32, const N: usize = 3,
+/// # >([T; N]);
+/// ```
+#[derive(Clone, Debug)]
+pub enum GenericParamDefault {
+    /// E.g. `u32` in `T = u32`.
+    Type(TypeExpr),
+    /// E.g. `3` in `const N: usize = 3`.
+    Const(ValueExpr),
 }
 
 /// A parameter bound in a type's generic list.
@@ -609,6 +778,13 @@ pub enum GenericArg {
         /// Note that it may also capture constants, e.g. `MyArray<32>` this would be `32`.
         ty: TypeExpr,
     },
+    /// E.g. `Iterator<Item: Clone + Send>`.
+    AssocBound {
+        /// For the above example, this would be `Item`.
+        ident: Ident,
+        /// For the above example, this would be `: Clone + Send`.
+        bound: GenericBound,
+    },
     /// E.g. `Rc<path::to::Type>` or `MyArray<32>`.  
     /// Since expressions are not parsed, the two cannot be differentiated.
     TypeOrConst { expr: TypeExpr },
@@ -690,6 +866,31 @@ pub struct Path {
     pub segments: Vec<PathSegment>,
 }
 
+/// A qualified path, as returned by [`TypeExpr::as_qualified_path()`].
+///
+/// **Example input:**
+///
+/// ```no_run
+/// # trait Trait { type Assoc<X>; }
+/// # struct MyStruct<T: Trait, X> {
+///     foo: <T as Trait>::Assoc<X>,
+/// # };
+/// ```
+#[derive(Clone, Debug)]
+pub struct QualifiedPath {
+    pub tk_l_bracket: Punct,
+    /// For the above example, this would be `T`.
+    pub self_ty: TypeExpr,
+    pub tk_as: Option<Ident>,
+    /// For the above example, this would be `Trait`.
+    pub trait_path: Option<Path>,
+    pub tk_r_bracket: Punct,
+    /// For the above example, this would be `::Assoc<X>`.
+    ///
+    /// The `::` after the closing bracket is stored in the first segment's `tk_separator_colons`.
+    pub path: Path,
+}
+
 /// A segment of a [`Path`], e.g. `Type::<i32>`
 #[derive(Clone)]
 pub struct PathSegment {
@@ -701,6 +902,82 @@ pub struct PathSegment {
     pub generic_args: Option<GenericArgList>,
 }
 
+/// A reference type, as returned by [`TypeExpr::as_reference()`].
+///
+/// **Example input:**
+///
+/// ```no_run
+/// # struct MyStruct<'a> {
+///     foo: &'a mut Vec<i32>,
+/// # };
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReferenceType {
+    pub tk_ref: Punct,
+    /// For the above example, this would be `'a`.
+    pub lifetime: Option<Lifetime>,
+    pub tk_mut: Option<Ident>,
+    /// For the above example, this would be `Vec<i32>`.
+    pub inner: TypeExpr,
+}
+
+/// A raw pointer type, as returned by [`TypeExpr::as_pointer()`].
+///
+/// **Example input:**
+///
+/// ```no_run
+/// # struct MyStruct {
+///     foo: *const u8,
+///     bar: *mut Vec<i32>,
+/// # };
+/// ```
+#[derive(Clone, Debug)]
+pub struct PointerType {
+    pub tk_star: Punct,
+    /// Either `const` or `mut`.
+    pub tk_const_or_mut: Ident,
+    /// For the above examples, this would be `u8` and `Vec<i32>`.
+    pub inner: TypeExpr,
+}
+
+/// A function pointer type, as returned by [`TypeExpr::as_fn_pointer()`].
+///
+/// **Example input:**
+///
+/// ```no_run
+/// # struct MyStruct {
+///     foo: fn(i32, f32) -> bool,
+///     bar: unsafe extern "C" fn(fmt: *const u8, ...),
+///     baz: for<'a> fn(&'a u8),
+/// # };
+/// ```
+#[derive(Clone, Debug)]
+pub struct FnPointerType {
+    pub tk_for: Option<Ident>,
+    /// For the above example, this would be `<'a>`.
+    pub for_lifetimes: Option<GenericParamList>,
+    pub tk_unsafe: Option<Ident>,
+    pub tk_extern: Option<Ident>,
+    /// ABI string after `extern`, e.g. `"C"`. Only present if `extern` is present.
+    pub extern_abi: Option<Literal>,
+    pub tk_fn: Ident,
+    pub tk_params_parens: GroupSpan,
+    pub params: Punctuated<FnPointerParam>,
+    /// The `...` of a variadic function pointer; always the last parameter.
+    pub tk_variadic: Option<[Punct; 3]>,
+    pub tk_return_arrow: Option<[Punct; 2]>,
+    pub return_ty: Option<TypeExpr>,
+}
+
+/// A parameter of a [`FnPointerType`], such as `i32` or `fmt: *const u8`.
+#[derive(Clone, Debug)]
+pub struct FnPointerParam {
+    /// Parameter names are optional in function pointer types.
+    pub name: Option<Ident>,
+    pub tk_colon: Option<Punct>,
+    pub ty: TypeExpr,
+}
+
 /// A `use` declaration for a path.
 ///
 /// See also <https://doc.rust-lang.org/reference/items/use-declarations.html>.
@@ -716,12 +993,55 @@ pub struct UseDeclaration {
     pub vis_marker: Option<VisMarker>,
     /// The `use` keyword
     pub tk_use: Ident,
-    /// Un-tokenized import paths between `use` and the finishing `;`
-    pub import_tree: TypeExpr,
+    /// Leading `::` of an absolute import such as `use ::std::mem;`
+    pub tk_leading_colons: Option<[Punct; 2]>,
+    /// Import paths between `use` and the finishing `;`
+    pub tree: UseTree,
     /// Semicolon ending the use statement
     pub tk_semicolon: Punct,
 }
 
+/// The imported paths of a [`UseDeclaration`].
+///
+/// For instance, `std::collections::{self, HashMap as Map}` is a chain of two [`UseTree::Path`],
+/// followed by a [`UseTree::Group`] containing a [`UseTree::Name`] and a [`UseTree::Rename`].
+#[derive(Clone, Debug)]
+pub enum UseTree {
+    /// E.g. `std::` in `std::mem`.
+    Path(UsePath),
+    /// E.g. `mem` in `std::mem`, or `self` in `std::{self}`.
+    Name(Ident),
+    /// E.g. `HashMap as Map`.
+    Rename(UseRename),
+    /// E.g. `*` in `std::mem::*`.
+    Glob(Punct),
+    /// E.g. `{Cell, RefCell}` in `std::cell::{Cell, RefCell}`.
+    Group(UseGroup),
+}
+
+/// A path segment followed by `::` and the rest of the tree, e.g. `std::mem`.
+#[derive(Clone)]
+pub struct UsePath {
+    pub ident: Ident,
+    pub tk_colon2: [Punct; 2],
+    pub tree: Box<UseTree>,
+}
+
+/// An import with an alias, e.g. `HashMap as Map` or `Trait as _`.
+#[derive(Clone, Debug)]
+pub struct UseRename {
+    pub ident: Ident,
+    pub tk_as: Ident,
+    pub rename: Ident,
+}
+
+/// A braced list of imports, e.g. `{Cell, RefCell}`.
+#[derive(Clone, Debug)]
+pub struct UseGroup {
+    pub tk_braces: GroupSpan,
+    pub items: Punctuated<UseTree>,
+}
+
 /// The value of an [`EnumVariant`], normally for c-like enums.
 ///
 /// **Example input:**
@@ -731,11 +1051,18 @@ pub struct UseDeclaration {
 ///     A = 42,
 ///     # #[cfg(FALSE)]
 ///     B = (some + arbitrary.expression()),
+///     # #[cfg(FALSE)]
+///     C = FLAG_A | FLAG_B,
 /// }
 /// ```
 #[derive(Clone, Debug)]
 pub struct EnumVariantValue {
     pub tk_equal: Punct,
+    /// The discriminant expression.
+    ///
+    /// Expressions of more than one token are wrapped in a [`Group`](proc_macro2::Group) with
+    /// [`Delimiter::None`](proc_macro2::Delimiter::None). Use [`EnumVariantValue::value_tokens()`]
+    /// to get the expression tokens directly.
     pub value: TokenTree,
 }
 
@@ -795,6 +1122,7 @@ pub struct Macro {
 ///    fn f();
 ///    static S: i32;
 /// }
+/// ```
 #[derive(Clone, Debug)]
 pub struct ExternBlock {
     /// Any attributes before the `extern` declaration.
@@ -851,11 +1179,30 @@ pub struct ExternCrate {
     pub tk_semicolon: Punct,
 }
 
+/// Item that venial doesn't know how to parse, kept as raw tokens.
+///
+/// Returned by [`parse_item_lenient()`](crate::parse_item_lenient) and
+/// [`consume_item_lenient()`](crate::consume_item_lenient), so that the item can be skipped or re-emitted as-is.
+/// This also applies to members of nested modules, impl blocks, traits and extern blocks.
+///
+/// **Example input:**
+///
+/// ```text
+/// pub some_future_keyword Foo { ... }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UnknownItem {
+    pub attributes: Vec<Attribute>,
+    pub vis_marker: Option<VisMarker>,
+    /// Remaining tokens, up to and including a `;` or `{ }` block.
+    pub tokens: Vec<TokenTree>,
+}
+
 /// Span information about a [`Group`].
 ///
 /// This can be used to recreate the group from its inner token sequence, or to create
 /// a new group with a modified token sequence but the original group's span information.
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 pub struct GroupSpan {
     pub delimiter: Delimiter,
     pub span: Span,
@@ -998,6 +1345,16 @@ impl std::fmt::Debug for PathSegment {
     }
 }
 
+impl std::fmt::Debug for UsePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UsePath")
+            .field("ident", &self.ident)
+            .field("tk_colon2", &"::")
+            .field("tree", &self.tree)
+            .finish()
+    }
+}
+
 impl std::fmt::Debug for GenericParamList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.params.fmt(f)
@@ -1011,7 +1368,13 @@ impl std::fmt::Debug for GenericParam {
             f.field("tk_prefix", &prefix.to_string());
         }
         f.field("name", &self.name.to_string());
+        if let Some((_tk_colon, const_ty)) = self.const_ty.as_ref() {
+            f.field("const_ty", const_ty);
+        }
         f.field("bound", &self.bound);
+        if let Some((_tk_equals, default)) = self.default.as_ref() {
+            f.field("default", default);
+        }
         f.finish()
     }
 }
@@ -1078,9 +1441,7 @@ impl GroupSpan {
     fn quote_with(&self, tokens: &mut TokenStream, f: impl FnOnce(&mut TokenStream)) {
         let mut inner = TokenStream::new();
         f(&mut inner);
-        let mut g = Group::new(self.delimiter, inner);
-        g.set_span(self.span);
-        tokens.append(g);
+        tokens.append(self.into_group(inner));
     }
 }
 
@@ -1100,6 +1461,7 @@ impl ToTokens for Item {
             Item::Macro(macro_decl) => macro_decl.to_tokens(tokens),
             Item::ExternBlock(block_decl) => block_decl.to_tokens(tokens),
             Item::ExternCrate(crate_decl) => crate_decl.to_tokens(tokens),
+            Item::Unknown(unknown) => unknown.to_tokens(tokens),
         }
     }
 }
@@ -1204,9 +1566,12 @@ impl ToTokens for TypeAlias {
         self.vis_marker.to_tokens(tokens);
         self.tk_type.to_tokens(tokens);
         self.name.to_tokens(tokens);
+        self.generic_params.to_tokens(tokens);
         self.bound.to_tokens(tokens);
+        self.where_clause.to_tokens(tokens);
         self.tk_equals.to_tokens(tokens);
         self.initializer_ty.to_tokens(tokens);
+        self.trailing_where_clause.to_tokens(tokens);
         self.tk_semicolon.to_tokens(tokens);
     }
 }
@@ -1257,6 +1622,7 @@ impl ToTokens for Trait {
         }
         self.vis_marker.to_tokens(tokens);
         self.tk_unsafe.to_tokens(tokens);
+        self.tk_auto.to_tokens(tokens);
         self.tk_trait.to_tokens(tokens);
         self.name.to_tokens(tokens);
         self.generic_params.to_tokens(tokens);
@@ -1282,6 +1648,7 @@ impl ToTokens for Impl {
         self.tk_unsafe.to_tokens(tokens);
         self.tk_impl.to_tokens(tokens);
         self.impl_generic_params.to_tokens(tokens);
+        self.tk_bang.to_tokens(tokens);
         self.trait_ty.to_tokens(tokens);
         self.tk_for.to_tokens(tokens);
         self.self_ty.to_tokens(tokens);
@@ -1305,6 +1672,7 @@ impl ToTokens for ImplMember {
             ImplMember::AssocConstant(constant) => constant.to_tokens(tokens),
             ImplMember::AssocType(assoc_ty) => assoc_ty.to_tokens(tokens),
             ImplMember::Macro(macro_) => macro_.to_tokens(tokens),
+            ImplMember::Unknown(unknown) => unknown.to_tokens(tokens),
         }
     }
 }
@@ -1316,6 +1684,7 @@ impl ToTokens for TraitMember {
             TraitMember::AssocConstant(constant) => constant.to_tokens(tokens),
             TraitMember::AssocType(assoc_ty) => assoc_ty.to_tokens(tokens),
             TraitMember::Macro(macro_) => macro_.to_tokens(tokens),
+            TraitMember::Unknown(unknown) => unknown.to_tokens(tokens),
         }
     }
 }
@@ -1461,6 +1830,21 @@ impl ToTokens for VisMarker {
     }
 }
 
+impl ToTokens for VisRestriction {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let keyword = match self {
+            VisRestriction::Crate => "crate",
+            VisRestriction::Super => "super",
+            VisRestriction::SelfMod => "self",
+            VisRestriction::In(_) => "in",
+        };
+        tokens.append(Ident::new(keyword, Span::call_site()));
+        if let VisRestriction::In(path) = self {
+            path.to_tokens(tokens);
+        }
+    }
+}
+
 impl ToTokens for Path {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         for segment in &self.segments {
@@ -1469,6 +1853,17 @@ impl ToTokens for Path {
     }
 }
 
+impl ToTokens for QualifiedPath {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append(self.tk_l_bracket.clone());
+        self.self_ty.to_tokens(tokens);
+        self.tk_as.to_tokens(tokens);
+        self.trait_path.to_tokens(tokens);
+        tokens.append(self.tk_r_bracket.clone());
+        self.path.to_tokens(tokens);
+    }
+}
+
 impl ToTokens for PathSegment {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         if let Some(colons) = &self.tk_separator_colons {
@@ -1481,6 +1876,53 @@ impl ToTokens for PathSegment {
     }
 }
 
+impl ToTokens for ReferenceType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.tk_ref.to_tokens(tokens);
+        self.lifetime.to_tokens(tokens);
+        self.tk_mut.to_tokens(tokens);
+        self.inner.to_tokens(tokens);
+    }
+}
+
+impl ToTokens for PointerType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.tk_star.to_tokens(tokens);
+        self.tk_const_or_mut.to_tokens(tokens);
+        self.inner.to_tokens(tokens);
+    }
+}
+
+impl ToTokens for FnPointerType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.tk_for.to_tokens(tokens);
+        self.for_lifetimes.to_tokens(tokens);
+        self.tk_unsafe.to_tokens(tokens);
+        self.tk_extern.to_tokens(tokens);
+        self.extern_abi.to_tokens(tokens);
+        self.tk_fn.to_tokens(tokens);
+        self.tk_params_parens.quote_with(tokens, |tokens| {
+            self.params.to_tokens(tokens);
+            if let Some(tk_variadic) = &self.tk_variadic {
+                tokens.append_all(tk_variadic);
+            }
+        });
+        if let Some([dash, tip]) = self.tk_return_arrow.as_ref() {
+            dash.to_tokens(tokens);
+            tip.to_tokens(tokens);
+        }
+        self.return_ty.to_tokens(tokens);
+    }
+}
+
+impl ToTokens for FnPointerParam {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.name.to_tokens(tokens);
+        self.tk_colon.to_tokens(tokens);
+        self.ty.to_tokens(tokens);
+    }
+}
+
 impl ToTokens for GenericParamList {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.append(self.tk_l_bracket.clone());
@@ -1493,7 +1935,24 @@ impl ToTokens for GenericParam {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.tk_prefix.to_tokens(tokens);
         self.name.to_tokens(tokens);
+        if let Some((tk_colon, const_ty)) = &self.const_ty {
+            tk_colon.to_tokens(tokens);
+            const_ty.to_tokens(tokens);
+        }
         self.bound.to_tokens(tokens);
+        if let Some((tk_equals, default)) = &self.default {
+            tk_equals.to_tokens(tokens);
+            default.to_tokens(tokens);
+        }
+    }
+}
+
+impl ToTokens for GenericParamDefault {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            GenericParamDefault::Type(ty) => ty.to_tokens(tokens),
+            GenericParamDefault::Const(value) => value.to_tokens(tokens),
+        }
     }
 }
 
@@ -1533,6 +1992,10 @@ impl ToTokens for GenericArg {
                 tk_equals.to_tokens(tokens);
                 ty.to_tokens(tokens);
             }
+            GenericArg::AssocBound { ident, bound } => {
+                ident.to_tokens(tokens);
+                bound.to_tokens(tokens);
+            }
             GenericArg::TypeOrConst { expr } => {
                 expr.to_tokens(tokens);
             }
@@ -1602,11 +2065,52 @@ impl ToTokens for UseDeclaration {
         }
         self.vis_marker.to_tokens(tokens);
         self.tk_use.to_tokens(tokens);
-        self.import_tree.to_tokens(tokens);
+        if let Some(colons) = &self.tk_leading_colons {
+            colons[0].to_tokens(tokens);
+            colons[1].to_tokens(tokens);
+        }
+        self.tree.to_tokens(tokens);
         self.tk_semicolon.to_tokens(tokens);
     }
 }
 
+impl ToTokens for UseTree {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            UseTree::Path(path) => path.to_tokens(tokens),
+            UseTree::Name(name) => name.to_tokens(tokens),
+            UseTree::Rename(rename) => rename.to_tokens(tokens),
+            UseTree::Glob(star) => star.to_tokens(tokens),
+            UseTree::Group(group) => group.to_tokens(tokens),
+        }
+    }
+}
+
+impl ToTokens for UsePath {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.ident.to_tokens(tokens);
+        self.tk_colon2[0].to_tokens(tokens);
+        self.tk_colon2[1].to_tokens(tokens);
+        self.tree.to_tokens(tokens);
+    }
+}
+
+impl ToTokens for UseRename {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.ident.to_tokens(tokens);
+        self.tk_as.to_tokens(tokens);
+        self.rename.to_tokens(tokens);
+    }
+}
+
+impl ToTokens for UseGroup {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.tk_braces.quote_with(tokens, |tokens| {
+            self.items.to_tokens(tokens);
+        });
+    }
+}
+
 impl ToTokens for EnumVariantValue {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.tk_equal.to_tokens(tokens);
@@ -1665,6 +2169,96 @@ impl ToTokens for ExternCrate {
     }
 }
 
+impl ToTokens for UnknownItem {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for attribute in &self.attributes {
+            attribute.to_tokens(tokens);
+        }
+        self.vis_marker.to_tokens(tokens);
+        tokens.append_all(&self.tokens);
+    }
+}
+
+impl ToTokens for CfgPredicate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (operator, predicates) = match self {
+            CfgPredicate::Option(name) => {
+                name.to_tokens(tokens);
+                return;
+            }
+            CfgPredicate::KeyValue(name, value) => {
+                name.to_tokens(tokens);
+                tokens.append(Punct::new('=', Spacing::Alone));
+                tokens.append(Literal::string(value));
+                return;
+            }
+            CfgPredicate::All(predicates) => ("all", predicates.as_slice()),
+            CfgPredicate::Any(predicates) => ("any", predicates.as_slice()),
+            CfgPredicate::Not(predicate) => ("not", std::slice::from_ref(predicate.as_ref())),
+        };
+
+        let mut inner = TokenStream::new();
+        inner.append_separated(predicates, Punct::new(',', Spacing::Alone));
+        tokens.append(Ident::new(operator, Span::call_site()));
+        tokens.append(Group::new(Delimiter::Parenthesis, inner));
+    }
+}
+
+impl ToTokens for Repr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_separated(&self.hints, Punct::new(',', Spacing::Alone));
+    }
+}
+
+impl ToTokens for ReprHint {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (name, arg) = match self {
+            ReprHint::C => ("C", None),
+            ReprHint::Transparent => ("transparent", None),
+            ReprHint::Packed(arg) => ("packed", *arg),
+            ReprHint::Align(arg) => ("align", Some(*arg)),
+            ReprHint::Int(ident) => {
+                ident.to_tokens(tokens);
+                return;
+            }
+        };
+
+        tokens.append(Ident::new(name, Span::call_site()));
+        if let Some(arg) = arg {
+            let arg = TokenTree::Literal(Literal::u64_unsuffixed(arg));
+            tokens.append(Group::new(Delimiter::Parenthesis, arg.into()));
+        }
+    }
+}
+
+impl ToTokens for Meta {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Meta::Path(path) => path.to_tokens(tokens),
+            Meta::List(list) => list.to_tokens(tokens),
+            Meta::NameValue {
+                path,
+                tk_equals,
+                value,
+            } => {
+                path.to_tokens(tokens);
+                tk_equals.to_tokens(tokens);
+                value.to_tokens(tokens);
+            }
+            Meta::Lit(literal) => literal.to_tokens(tokens),
+        }
+    }
+}
+
+impl ToTokens for MetaList {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.path.to_tokens(tokens);
+        self.tk_parens.quote_with(tokens, |tokens| {
+            self.items.to_tokens(tokens);
+        });
+    }
+}
+
 // --- Default impls ---
 
 impl Default for GenericParamList {
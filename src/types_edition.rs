@@ -1,13 +1,24 @@
-use crate::parse_utils::{consume_path, tokens_from_slice};
+use crate::error::Error;
+use crate::parse_meta::parse_meta_list;
+use crate::parse_type::{consume_generic_params, consume_lifetime, parse_bound_list};
+use crate::parse_utils::{
+    consume_any_ident, consume_cfg_predicate, consume_comma, consume_ident, consume_path,
+    consume_punct, consume_stuff_until, int_literal_value, signed_int_value, string_literal_value,
+    tokens_from_slice, INT_SUFFIXES,
+};
 use crate::types::{
-    Attribute, AttributeValue, Constant, Enum, EnumVariant, EnumVariantValue, ExternBlock,
-    ExternCrate, Fields, FnQualifiers, Function, GenericArg, GenericArgList, GenericBound,
-    GenericParam, GenericParamList, GroupSpan, Impl, InlineGenericArgs, Item, Lifetime, Macro,
-    Module, NamedField, Path, Punctuated, Struct, Trait, TupleField, TypeAlias, TypeExpr, Union,
-    UseDeclaration, VisMarker, WhereClause, WhereClausePredicate,
+    Attribute, AttributeValue, CfgPredicate, Constant, Enum, EnumVariant, EnumVariantValue,
+    ExternBlock, ExternCrate, Fields, FnPointerParam, FnPointerType, FnQualifiers, FnReceiverParam,
+    FnTypedParam, Function, GenericArg, GenericArgList, GenericBound, GenericParam,
+    GenericParamKind, GenericParamList, GroupSpan, Impl, InlineGenericArgs, Item, Lifetime, Macro,
+    Meta, MetaList, Module, NamedField, Path, PointerType, Punctuated, QualifiedPath,
+    ReferenceType, Repr, ReprHint, Struct, Trait, TupleField, TupleFields, TypeAlias, TypeExpr,
+    Union, UnknownItem, UseDeclaration, ValueExpr, VisMarker, VisRestriction, WhereClause,
+    WhereClausePredicate,
 };
 use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::spanned::Spanned;
+use quote::ToTokens;
 
 impl Item {
     /// Returns the [`Vec<Attribute>`] of the declaration.
@@ -28,6 +39,7 @@ impl Item {
             Item::Macro(macro_decl) => &macro_decl.attributes,
             Item::ExternBlock(block_decl) => &block_decl.attributes,
             Item::ExternCrate(crate_decl) => &crate_decl.attributes,
+            Item::Unknown(unknown) => &unknown.attributes,
         }
     }
 
@@ -49,6 +61,49 @@ impl Item {
             Item::Macro(macro_decl) => &mut macro_decl.attributes,
             Item::ExternBlock(block_decl) => &mut block_decl.attributes,
             Item::ExternCrate(crate_decl) => &mut crate_decl.attributes,
+            Item::Unknown(unknown) => &mut unknown.attributes,
+        }
+    }
+
+    /// Returns true if one of the declaration's attributes has the given path, see [`Attribute::path_is()`].
+    pub fn has_attribute(&self, path: &str) -> bool {
+        self.attributes()
+            .iter()
+            .any(|attribute| attribute.path_is(path))
+    }
+
+    /// Returns the declaration's attributes, except the ones with the given path, see [`Attribute::path_is()`].
+    ///
+    /// This is useful to re-emit attributes, after handling a macro-specific attribute.
+    pub fn without_attribute(&self, path: &str) -> Vec<Attribute> {
+        self.attributes()
+            .iter()
+            .filter(|attribute| !attribute.path_is(path))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the [`VisMarker`] of the declaration, if any.
+    ///
+    /// `impl` blocks and macros never have a visibility marker.
+    ///
+    /// This method is provided for convenience, but it's more idiomatic to match on Declaration and use the same method in the matching variant.
+    pub fn vis_marker(&self) -> Option<&VisMarker> {
+        match self {
+            Item::Struct(struct_decl) => struct_decl.vis_marker.as_ref(),
+            Item::Enum(enum_decl) => enum_decl.vis_marker.as_ref(),
+            Item::Union(union_decl) => union_decl.vis_marker.as_ref(),
+            Item::Module(mod_decl) => mod_decl.vis_marker.as_ref(),
+            Item::Trait(trait_decl) => trait_decl.vis_marker.as_ref(),
+            Item::Impl(_) => None,
+            Item::TypeAlias(ty_decl) => ty_decl.vis_marker.as_ref(),
+            Item::Function(function_decl) => function_decl.vis_marker.as_ref(),
+            Item::Constant(const_decl) => const_decl.vis_marker.as_ref(),
+            Item::UseDeclaration(use_decl) => use_decl.vis_marker.as_ref(),
+            Item::Macro(_) => None,
+            Item::ExternBlock(block_decl) => block_decl.vis_marker.as_ref(),
+            Item::ExternCrate(crate_decl) => crate_decl.vis_marker.as_ref(),
+            Item::Unknown(unknown) => unknown.vis_marker.as_ref(),
         }
     }
 
@@ -57,7 +112,7 @@ impl Item {
     /// For instance, this will return Some for `struct MyStruct<A, B, C> { ... }`,
     /// Some for `impl<A> MyTrait for MyType<A>` and None for `enum MyEnum { ... }`.
     ///
-    /// `TyDefinition` and `Constant` variants never have a generic parameter list.
+    /// `Constant` variants never have a generic parameter list.
     ///
     /// This method is provided for convenience, but it's more idiomatic to match on Declaration and use the same method in the matching variant.
     pub fn generic_params(&self) -> Option<&GenericParamList> {
@@ -68,13 +123,14 @@ impl Item {
             Item::Module(_) => None,
             Item::Trait(trait_decl) => trait_decl.generic_params.as_ref(),
             Item::Impl(impl_decl) => impl_decl.impl_generic_params.as_ref(),
-            Item::TypeAlias(_) => None,
+            Item::TypeAlias(ty_decl) => ty_decl.generic_params.as_ref(),
             Item::Function(function_decl) => function_decl.generic_params.as_ref(),
             Item::Constant(_) => None,
             Item::UseDeclaration(_) => None,
             Item::Macro(_) => None,
             Item::ExternBlock(_) => None,
             Item::ExternCrate(_) => None,
+            Item::Unknown(_) => None,
         }
     }
 
@@ -83,7 +139,7 @@ impl Item {
     /// For instance, this will return Some for `struct MyStruct<A, B, C> { ... }`,
     /// Some for `impl<A> MyTrait for MyType<A>` and None for `enum MyEnum { ... }`.
     ///
-    /// `TyDefinition` and `Constant` variants never have a generic parameter list.
+    /// `Constant` variants never have a generic parameter list.
     ///
     /// This method is provided for convenience, but it's more idiomatic to match on Declaration and use the same method in the matching variant.
     pub fn generic_params_mut(&mut self) -> Option<&mut GenericParamList> {
@@ -94,13 +150,14 @@ impl Item {
             Item::Module(_) => None,
             Item::Trait(trait_decl) => trait_decl.generic_params.as_mut(),
             Item::Impl(impl_decl) => impl_decl.impl_generic_params.as_mut(),
-            Item::TypeAlias(_) => None,
+            Item::TypeAlias(ty_decl) => ty_decl.generic_params.as_mut(),
             Item::Function(function_decl) => function_decl.generic_params.as_mut(),
             Item::Constant(_) => None,
             Item::UseDeclaration(_) => None,
             Item::Macro(_) => None,
             Item::ExternBlock(_) => None,
             Item::ExternCrate(_) => None,
+            Item::Unknown(_) => None,
         }
     }
 
@@ -132,9 +189,17 @@ impl Item {
             Item::Macro(macro_) => Some(macro_.name.clone()),
             Item::ExternBlock(_) => None,
             Item::ExternCrate(crate_) => Some(crate_.name.clone()),
+            Item::Unknown(_) => None,
         }
     }
 
+    /// Returns the span of the declaration's name, if available.
+    ///
+    /// This is useful for diagnostics about the declared item as a whole, see [`Self::name()`].
+    pub fn name_span(&self) -> Option<Span> {
+        self.name().map(|name| name.span())
+    }
+
     /// Returns the [`Struct`] variant of the enum if possible.
     pub fn as_struct(&self) -> Option<&Struct> {
         match self {
@@ -341,10 +406,68 @@ impl Struct {
             }
         }
     }
+
+    /// Returns true for unit structs, e.g. `struct Foo;`.
+    ///
+    /// Structs with empty braces or parentheses, such as `struct Foo {}`, are not unit structs.
+    pub fn is_unit_struct(&self) -> bool {
+        matches!(self.fields, Fields::Unit)
+    }
+
+    /// Returns true for tuple structs, e.g. `struct Foo(i32, String);`.
+    pub fn is_tuple_struct(&self) -> bool {
+        matches!(self.fields, Fields::Tuple(_))
+    }
+
+    /// Returns true for structs with named fields, e.g. `struct Foo { x: i32 }`.
+    pub fn is_named_struct(&self) -> bool {
+        matches!(self.fields, Fields::Named(_))
+    }
+
+    /// Returns the number of fields; 0 for unit structs.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use quote::quote;
+    /// let struct_type = parse_item(quote!(
+    ///     struct Foo;
+    /// )).unwrap();
+    /// let struct_type = struct_type.as_struct().unwrap();
+    /// assert!(struct_type.is_unit_struct());
+    /// assert_eq!(struct_type.field_count(), 0);
+    /// ```
+    pub fn field_count(&self) -> usize {
+        self.fields.field_count()
+    }
 }
 
 impl Enum {
-    /// Returns true if every single variant is empty.
+    /// Returns an iterator over the variants, without their separators.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use quote::quote;
+    /// let enum_type = parse_item(quote!(
+    ///     enum MyEnum { A, B(i32), C { x: u8 } }
+    /// )).unwrap();
+    /// let enum_type = enum_type.as_enum().unwrap();
+    ///
+    /// let names: Vec<_> = enum_type.variants().map(|variant| variant.name.to_string()).collect();
+    /// assert_eq!(names, ["A", "B", "C"]);
+    /// assert_eq!(enum_type.variant_count(), 3);
+    /// ```
+    pub fn variants(&self) -> impl Iterator<Item = &EnumVariant> {
+        self.variants.items()
+    }
+
+    /// Returns the number of variants.
+    pub fn variant_count(&self) -> usize {
+        self.variants.len()
+    }
+
+    /// Returns true if every single variant is empty, i.e. the enum is C-like.
+    ///
+    /// An enum without variants is considered C-like, so this returns true.
     ///
     /// ```
     /// # use venial::parse_item;
@@ -363,6 +486,48 @@ impl Enum {
         }
         true
     }
+
+    /// Returns true if at least one variant has an explicit discriminant, such as `A = 1`.
+    pub fn has_discriminants(&self) -> bool {
+        self.variants.items().any(|variant| variant.value.is_some())
+    }
+
+    /// Returns the explicit discriminant of each variant, in order, or None for variants without one.
+    pub fn explicit_discriminants(&self) -> Vec<Option<&EnumVariantValue>> {
+        self.variants
+            .items()
+            .map(|variant| variant.value.as_ref())
+            .collect()
+    }
+
+    /// Returns the numeric discriminant of each variant, in order.
+    ///
+    /// Variants without explicit discriminant get the previous value plus one, starting at 0.
+    /// Returns None if any explicit discriminant is not an integer literal (see [`EnumVariantValue::value_int()`]),
+    /// or if a value overflows `i64`.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use quote::quote;
+    /// let enum_type = parse_item(quote!(
+    ///     enum MyEnum { A, B = 5, C, D = -1, E }
+    /// )).unwrap();
+    /// let enum_type = enum_type.as_enum().unwrap();
+    /// assert_eq!(enum_type.inferred_discriminants(), Some(vec![0, 5, 6, -1, 0]));
+    /// ```
+    pub fn inferred_discriminants(&self) -> Option<Vec<i64>> {
+        let mut next_value = Some(0i64);
+        let mut values = Vec::new();
+        for variant in self.variants.items() {
+            let value = match &variant.value {
+                Some(value) => value.value_int()?,
+                None => next_value?,
+            };
+            values.push(value);
+            next_value = value.checked_add(1);
+        }
+        Some(values)
+    }
 }
 
 macro_rules! implement_common_methods {
@@ -484,6 +649,31 @@ implement_common_methods! { Struct }
 implement_common_methods! { Enum }
 implement_common_methods! { Union }
 
+impl UseDeclaration {
+    /// Returns the un-tokenized import paths between `use` and `;`, including leading `::`.
+    ///
+    /// This is the content of the former `import_tree` field; prefer [`UseDeclaration::tree`] for structured access.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use quote::quote;
+    /// let use_decl = parse_item(quote!(use ::std::{mem, fmt::Debug};)).unwrap();
+    /// let import_tree = use_decl.as_use_declaration().unwrap().import_tree();
+    /// assert_eq!(quote!(#import_tree).to_string(), ":: std :: { mem , fmt :: Debug }");
+    /// ```
+    pub fn import_tree(&self) -> TypeExpr {
+        let mut tokens = TokenStream::new();
+        if let Some(colons) = &self.tk_leading_colons {
+            colons[0].to_tokens(&mut tokens);
+            colons[1].to_tokens(&mut tokens);
+        }
+        self.tree.to_tokens(&mut tokens);
+        TypeExpr {
+            tokens: tokens.into_iter().collect(),
+        }
+    }
+}
+
 impl Attribute {
     /// Returns Some if the attribute has a single path segment, eg `#[hello(...)]`.
     /// Returns None if the attribute has multiple segments, eg `#[hello::world(...)]`.
@@ -503,186 +693,951 @@ impl Attribute {
         }
     }
 
-    /// Returns `foo + bar` for `#[hello = foo + bar]` and `#[hello(foo + bar)]`.
-    /// Returns an empty slice for `#[hello]`.
-    pub fn get_value_tokens(&self) -> &[TokenTree] {
-        self.value.get_value_tokens()
+    /// Returns the path of the attribute as a [`Path`], e.g. `serde` in `#[serde(rename = "x")]`
+    /// or `path::to::attr` in `#[path::to::attr]`.
+    ///
+    /// Returns None if [`Self::path`] was modified into something that is not a path.
+    pub fn get_path(&self) -> Option<Path> {
+        consume_path(tokens_from_slice(&self.path))
+    }
+
+    /// Returns true if the attribute's path is `path`, e.g. `"skip"` for `#[skip]` or `"serde::skip"` for `#[serde::skip]`.
+    ///
+    /// Arguments of the attribute are not compared.
+    pub fn path_is(&self, path: &str) -> bool {
+        path_tokens_are(&self.path, path)
     }
-}
 
-impl AttributeValue {
     /// Returns `foo + bar` for `#[hello = foo + bar]` and `#[hello(foo + bar)]`.
     /// Returns an empty slice for `#[hello]`.
+    ///
+    /// The tokens are borrowed verbatim, without the path and the surrounding delimiters. This is the entry point
+    /// for attributes with a custom argument grammar; see [`Self::parse_meta()`] for the common `a, b = c, d(e)` form.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use proc_macro2::TokenStream;
+    /// # use quote::quote;
+    /// let struct_type = parse_item(quote!(
+    ///     #[route(GET "/users" => list_users)]
+    ///     struct Foo;
+    /// )).unwrap();
+    /// let tokens = struct_type.attributes()[0].get_value_tokens();
+    /// let stream: TokenStream = tokens.iter().cloned().collect();
+    /// assert_eq!(stream.to_string(), quote!(GET "/users" => list_users).to_string());
+    /// ```
     pub fn get_value_tokens(&self) -> &[TokenTree] {
-        match self {
-            AttributeValue::Group(_, tokens) => tokens,
-            AttributeValue::Equals(_, tokens) => tokens,
-            AttributeValue::Empty => &[],
-        }
+        self.value.get_value_tokens()
     }
-}
 
-impl EnumVariant {
-    /// Returns true if the variant doesn't store a type.
-    pub fn is_empty_variant(&self) -> bool {
-        matches!(self.fields, Fields::Unit)
+    /// Returns true for inner attributes such as `#![allow(unused)]`, false for outer attributes such as `#[allow(unused)]`.
+    pub fn is_inner(&self) -> bool {
+        self.tk_bang.is_some()
     }
 
-    /// Returns Some if the variant is a wrapper around a single type.
-    /// Returns None otherwise.
-    pub fn get_single_type(&self) -> Option<&TupleField> {
-        match &self.fields {
-            Fields::Tuple(fields) if fields.fields.len() == 1 => Some(&fields.fields[0].0),
-            Fields::Tuple(_fields) => None,
-            Fields::Unit => None,
-            Fields::Named(_) => None,
+    /// Parses the predicate of a `#[cfg(...)]` attribute.
+    ///
+    /// Returns None if the attribute is not `cfg` or the predicate is malformed.
+    ///
+    /// ```
+    /// # use venial::{parse_item, CfgPredicate};
+    /// # use quote::quote;
+    /// let struct_type = parse_item(quote!(
+    ///     #[cfg(not(feature = "std"))]
+    ///     struct Foo;
+    /// )).unwrap();
+    /// let cfg = struct_type.attributes()[0].as_cfg().unwrap();
+    /// let CfgPredicate::Not(inner) = cfg else { panic!() };
+    /// assert!(matches!(*inner, CfgPredicate::KeyValue(key, value) if key == "feature" && value == "std"));
+    /// ```
+    pub fn as_cfg(&self) -> Option<CfgPredicate> {
+        match (self.get_single_path_segment(), &self.value) {
+            (Some(ident), AttributeValue::Group(group, tokens))
+                if ident == "cfg" && group.delimiter == Delimiter::Parenthesis =>
+            {
+                let mut tokens = tokens_from_slice(tokens);
+                let predicate = consume_cfg_predicate(&mut tokens)?;
+                // Allow trailing comma, as rustc does
+                consume_comma(&mut tokens);
+                match tokens.peek() {
+                    None => Some(predicate),
+                    Some(_) => None,
+                }
+            }
+            _ => None,
         }
     }
-}
-
-impl FnQualifiers {
-    /// Whether exactly either `const` or `unsafe` attribute is set, and no other one
-    /// (so the tokens could be the start of a constant or impl declaration)
-    pub(crate) fn has_only_const_xor_unsafe(&self) -> bool {
-        (self.tk_const.is_some() ^ self.tk_unsafe.is_some())
-            && self.tk_default.is_none()
-            && self.tk_async.is_none()
-            && self.tk_extern.is_none()
-            && self.extern_abi.is_none()
-    }
-}
 
-impl GenericParamList {
-    /// Builder method, add a [`GenericParam`].
+    /// Returns the derived trait paths of a `#[derive(...)]` attribute.
     ///
-    /// Add lifetime params to the beginning of the list.
-    pub fn with_param(mut self, param: GenericParam) -> Self {
-        if param.is_lifetime() {
-            self.params.insert(0, param, None);
-        } else {
-            self.params.push(param, None);
-        }
-        self
-    }
+    /// For `#[derive(Clone, serde::Serialize)]`, this returns the paths `Clone` and `serde::Serialize`.
+    /// Returns None if the attribute is not `derive` or its arguments aren't a list of paths.
+    pub fn as_derive(&self) -> Option<Punctuated<Path>> {
+        let tokens = match (self.get_single_path_segment(), &self.value) {
+            (Some(ident), AttributeValue::Group(group, tokens))
+                if ident == "derive" && group.delimiter == Delimiter::Parenthesis =>
+            {
+                tokens
+            }
+            _ => return None,
+        };
 
-    /// See [`InlineGenericArgs`] for details.
-    pub fn as_inline_args(&self) -> InlineGenericArgs<'_> {
-        InlineGenericArgs(self)
+        let mut tokens = tokens_from_slice(tokens);
+        let mut paths = Punctuated::new();
+        while tokens.peek().is_some() {
+            let path_tokens = consume_stuff_until(
+                &mut tokens,
+                |token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','),
+                false,
+            );
+            let path = consume_path(tokens_from_slice(&path_tokens))?;
+            paths.push(path, consume_comma(&mut tokens));
+        }
+        Some(paths)
     }
-}
 
-impl GenericParam {
-    /// Create new lifetime param from name.
+    /// Parses the hints of a `#[repr(...)]` attribute, such as `C` and `packed(2)` in `#[repr(C, packed(2))]`.
     ///
-    /// ```
-    /// # use venial::GenericParam;
-    /// GenericParam::lifetime("a")
-    /// # ;
-    /// ```
-    pub fn lifetime(name: &str) -> Self {
-        let lifetime_ident = Ident::new(name, Span::call_site());
-        GenericParam {
-            tk_prefix: Some(Punct::new('\'', Spacing::Joint).into()),
-            name: lifetime_ident,
-            bound: None,
+    /// Returns None if the attribute is not `repr` or contains unknown hints.
+    pub fn as_repr(&self) -> Option<Repr> {
+        let tokens = match (self.get_single_path_segment(), &self.value) {
+            (Some(ident), AttributeValue::Group(group, tokens))
+                if ident == "repr" && group.delimiter == Delimiter::Parenthesis =>
+            {
+                tokens
+            }
+            _ => return None,
+        };
+
+        let mut tokens = tokens_from_slice(tokens);
+        let mut hints = vec![];
+        while let Some(name) = consume_any_ident(&mut tokens) {
+            let arg = match tokens.peek() {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    let arg = match group.stream().into_iter().collect::<Vec<_>>().as_slice() {
+                        [TokenTree::Literal(literal)] => int_literal_value(literal)?,
+                        _ => return None,
+                    };
+                    tokens.next();
+                    Some(arg)
+                }
+                _ => None,
+            };
+
+            let hint = match (name.to_string().as_str(), arg) {
+                ("C", None) => ReprHint::C,
+                ("transparent", None) => ReprHint::Transparent,
+                ("packed", arg) => ReprHint::Packed(arg),
+                ("align", Some(arg)) => ReprHint::Align(arg),
+                (int, None) if INT_SUFFIXES.contains(&int) => ReprHint::Int(name),
+                _ => return None,
+            };
+            hints.push(hint);
+
+            if consume_comma(&mut tokens).is_none() {
+                break;
+            }
+        }
+
+        match tokens.peek() {
+            None => Some(Repr { hints }),
+            Some(_) => None,
         }
     }
 
-    /// Create new lifetime param from name and bound.
+    /// Parses the attribute into a structured [`Meta`], similar to syn's `Attribute::parse_meta()`.
+    ///
+    /// `#[hello]` gives [`Meta::Path`], `#[hello = "world"]` gives [`Meta::NameValue`] and
+    /// `#[hello(a, b = 1, c(d))]` gives [`Meta::List`], whose items are parsed recursively.
     ///
     /// ```
-    /// # use venial::GenericParam;
+    /// # use venial::{parse_item, Meta};
     /// # use quote::quote;
-    /// GenericParam::bounded_lifetime("a", quote!(b + c).into_iter().collect())
-    /// # ;
+    /// let struct_type = parse_item(quote!(
+    ///     #[serde(rename = "bar", default)]
+    ///     struct Foo;
+    /// )).unwrap();
+    /// let Meta::List(list) = struct_type.attributes()[0].parse_meta().unwrap() else { panic!() };
+    /// assert_eq!(list.items.len(), 2);
+    /// assert!(matches!(list.items[0].0, Meta::NameValue { .. }));
+    /// assert!(matches!(list.items[1].0, Meta::Path(_)));
     /// ```
-    pub fn bounded_lifetime(name: &str, bound: Vec<TokenTree>) -> Self {
-        let lifetime_ident = Ident::new(name, Span::call_site());
-        GenericParam {
-            tk_prefix: Some(Punct::new('\'', Spacing::Alone).into()),
-            name: lifetime_ident,
-            bound: Some(GenericBound {
-                tk_colon: Punct::new(':', Spacing::Alone),
-                tokens: bound,
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the attribute path is not a path, if a `=` isn't followed by a value,
+    /// or if the arguments are not a comma-separated list of paths, `name = value` pairs,
+    /// `name(...)` lists and literals.
+    pub fn parse_meta(&self) -> Result<Meta, Error> {
+        let path = match self.get_path() {
+            Some(path) => path,
+            None => {
+                let path_tokens: TokenStream = self.path.iter().cloned().collect();
+                return Err(Error::new_at_tokens(
+                    &path_tokens,
+                    format!(
+                        "cannot parse attribute: expected path, found `{}`",
+                        path_tokens
+                    ),
+                ));
+            }
+        };
+
+        match &self.value {
+            AttributeValue::Group(tk_parens, tokens) => Ok(Meta::List(MetaList {
+                path,
+                tk_parens: *tk_parens,
+                items: parse_meta_list(tokens.iter().cloned().collect())?,
+            })),
+            AttributeValue::Equals(tk_equals, tokens) if tokens.is_empty() => {
+                Err(Error::new_at_span(
+                    tk_equals.span(),
+                    "cannot parse attribute: expected value after `=`",
+                ))
+            }
+            AttributeValue::Equals(tk_equals, tokens) => Ok(Meta::NameValue {
+                path,
+                tk_equals: tk_equals.clone(),
+                value: ValueExpr {
+                    tokens: tokens.clone(),
+                },
             }),
+            AttributeValue::Empty => Ok(Meta::Path(path)),
         }
     }
 
-    /// Create new type param from name.
+    /// Returns the unescaped string of a `#[name = "value"]` or `#[name(key = "value")]` attribute.
     ///
-    /// ```
-    /// # use venial::GenericParam;
-    /// GenericParam::ty("T")
-    /// # ;
-    /// ```
-    pub fn ty(name: &str) -> Self {
-        let ty_ident = Ident::new(name, Span::call_site());
-        GenericParam {
-            tk_prefix: None,
-            name: ty_ident,
-            bound: None,
+    /// Returns None if the value is not a single string literal after `=`.
+    /// For lists with several keys, use [`Attribute::parse_meta()`] and [`MetaList::get()`] instead.
+    pub fn value_string(&self) -> Option<String> {
+        match self.value_after_equals()? {
+            [TokenTree::Literal(literal)] => string_literal_value(literal),
+            _ => None,
         }
     }
 
-    /// Create new type param from name and bound.
+    /// Returns the integer of a `#[name = 42]` or `#[name(key = -1)]` attribute.
     ///
-    /// ```
-    /// # use venial::GenericParam;
-    /// # use quote::quote;
-    /// GenericParam::bounded_ty("T", quote!(Debug + Eq).into_iter().collect())
-    /// # ;
-    /// ```
-    pub fn bounded_ty(name: &str, bound: Vec<TokenTree>) -> Self {
-        let ty_ident = Ident::new(name, Span::call_site());
-        GenericParam {
-            tk_prefix: None,
-            name: ty_ident,
-            bound: Some(GenericBound {
-                tk_colon: Punct::new(':', Spacing::Alone),
-                tokens: bound,
-            }),
+    /// Returns None if the value is not a single integer literal after `=`, possibly negated, or doesn't fit into `i64`.
+    /// For lists with several keys, use [`Attribute::parse_meta()`] and [`MetaList::get()`] instead.
+    pub fn value_int(&self) -> Option<i64> {
+        signed_int_value(self.value_after_equals()?)
+    }
+
+    /// Returns the tokens after `=`, either at the top level or in a single `key = value` list.
+    fn value_after_equals(&self) -> Option<&[TokenTree]> {
+        match &self.value {
+            AttributeValue::Equals(_, tokens) => Some(tokens),
+            AttributeValue::Group(_, tokens) => match tokens.as_slice() {
+                [TokenTree::Ident(_), TokenTree::Punct(equals), value @ ..]
+                    if equals.as_char() == '=' && equals.spacing() == Spacing::Alone =>
+                {
+                    Some(value)
+                }
+                _ => None,
+            },
+            AttributeValue::Empty => None,
         }
     }
 
-    /// Create new const param from name and type.
+    /// Returns true if this is a doc comment, i.e. `/// ...`, `//! ...` or the equivalent `#[doc = "..."]`.
+    pub fn is_doc_comment(&self) -> bool {
+        self.doc_string().is_some()
+    }
+
+    /// Returns the unescaped text of a doc comment.
+    ///
+    /// For `/// Hello` this is `" Hello"`, including the leading space.
+    /// Returns None if the attribute is not a doc comment.
     ///
     /// ```
-    /// # use venial::GenericParam;
+    /// # use venial::parse_item;
     /// # use quote::quote;
-    /// GenericParam::const_param("N", quote!(i32).into_iter().collect())
-    /// # ;
+    /// let struct_type = parse_item(quote!(
+    ///     /// Hello "world"
+    ///     struct Foo;
+    /// )).unwrap();
+    /// let doc = struct_type.attributes()[0].doc_string();
+    /// assert_eq!(doc.as_deref(), Some(" Hello \"world\""));
     /// ```
-    pub fn const_param(name: &str, ty: Vec<TokenTree>) -> Self {
-        let lifetime_ident = Ident::new(name, Span::call_site());
-        GenericParam {
-            tk_prefix: Some(Ident::new("const", Span::call_site()).into()),
-            name: lifetime_ident,
-            bound: Some(GenericBound {
-                tk_colon: Punct::new(':', Spacing::Alone),
-                tokens: ty,
-            }),
+    pub fn doc_string(&self) -> Option<String> {
+        match self.get_single_path_segment() {
+            Some(ident) if ident == "doc" && matches!(self.value, AttributeValue::Equals(..)) => {
+                self.value_string()
+            }
+            _ => None,
+        }
+    }
+}
+
+impl AttributeValue {
+    /// Returns `foo + bar` for `#[hello = foo + bar]` and `#[hello(foo + bar)]`.
+    /// Returns an empty slice for `#[hello]`.
+    pub fn get_value_tokens(&self) -> &[TokenTree] {
+        match self {
+            AttributeValue::Group(_, tokens) => tokens,
+            AttributeValue::Equals(_, tokens) => tokens,
+            AttributeValue::Empty => &[],
+        }
+    }
+}
+
+impl Meta {
+    /// Returns the path of the item, e.g. `rename` in `rename = "x"`.
+    ///
+    /// Returns None for [`Meta::Lit`].
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Meta::Path(path) => Some(path),
+            Meta::List(list) => Some(&list.path),
+            Meta::NameValue { path, .. } => Some(path),
+            Meta::Lit(_) => None,
+        }
+    }
+
+    /// Returns true if the item's path is `path`, e.g. `"rename"` for `rename = "x"` or `"a::b"` for `a::b(c)`.
+    pub fn path_is(&self, path: &str) -> bool {
+        match self.path() {
+            Some(own_path) => {
+                let own_tokens: Vec<TokenTree> = own_path.to_token_stream().into_iter().collect();
+                path_tokens_are(&own_tokens, path)
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the value of a `name = value` item, e.g. `"x"` in `rename = "x"`.
+    pub fn value(&self) -> Option<&ValueExpr> {
+        match self {
+            Meta::NameValue { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Returns true if the tokens spell out `path` without whitespace, e.g. `a`, `:`, `:`, `b` for `"a::b"`.
+fn path_tokens_are(tokens: &[TokenTree], path: &str) -> bool {
+    let mut rest = path;
+    for token in tokens {
+        match rest.strip_prefix(token.to_string().as_str()) {
+            Some(remaining) => rest = remaining,
+            None => return false,
+        }
+    }
+    rest.is_empty()
+}
+
+impl MetaList {
+    /// Returns all items named `name`, in order of appearance.
+    ///
+    /// Duplicate keys are not an error at parse level, so all of them are returned and the caller decides
+    /// whether to reject, merge or pick one of them.
+    ///
+    /// ```
+    /// # use venial::{parse_item, Meta};
+    /// # use quote::{quote, ToTokens};
+    /// let struct_type = parse_item(quote!(
+    ///     #[foo(a = 1, b, c(nested), a = 2,)]
+    ///     struct Foo;
+    /// )).unwrap();
+    /// let Meta::List(list) = struct_type.attributes()[0].parse_meta().unwrap() else { panic!() };
+    ///
+    /// let a_values: Vec<String> = list
+    ///     .get("a")
+    ///     .iter()
+    ///     .map(|meta| meta.value().unwrap().to_token_stream().to_string())
+    ///     .collect();
+    /// assert_eq!(a_values, ["1", "2"]);
+    /// assert!(matches!(list.get("c")[..], [Meta::List(_)]));
+    /// assert!(list.get("d").is_empty());
+    /// ```
+    pub fn get(&self, name: &str) -> Vec<&Meta> {
+        self.items
+            .items()
+            .filter(|item| item.path_is(name))
+            .collect()
+    }
+}
+
+impl VisMarker {
+    /// Creates an unrestricted `pub` marker with call-site span.
+    pub fn pub_() -> Self {
+        Self {
+            tk_token1: TokenTree::Ident(Ident::new("pub", Span::call_site())),
+            tk_token2: None,
+        }
+    }
+
+    /// Returns true for unrestricted `pub`, false for e.g. `pub(crate)`.
+    pub fn is_pub(&self) -> bool {
+        matches!(&self.tk_token1, TokenTree::Ident(ident) if ident == "pub")
+            && self.tk_token2.is_none()
+    }
+
+    /// Returns the restriction of the visibility, e.g. [`VisRestriction::Crate`] for `pub(crate)`.
+    ///
+    /// Returns None for unrestricted `pub`, or if the restriction can't be parsed.
+    ///
+    /// ```
+    /// # use venial::{parse_item, VisRestriction};
+    /// # use quote::quote;
+    /// let struct_type = parse_item(quote!(
+    ///     pub(in crate::foo) struct Hello;
+    /// )).unwrap();
+    /// let vis_marker = struct_type.as_struct().unwrap().vis_marker.as_ref().unwrap();
+    /// let Some(VisRestriction::In(path)) = vis_marker.restriction() else { panic!() };
+    /// assert_eq!(path.segments.len(), 2);
+    /// ```
+    pub fn restriction(&self) -> Option<VisRestriction> {
+        let group = match (&self.tk_token1, &self.tk_token2) {
+            // Legacy `crate` visibility, equivalent to `pub(crate)`
+            (TokenTree::Ident(ident), None) if ident == "crate" => {
+                return Some(VisRestriction::Crate)
+            }
+            (_, Some(TokenTree::Group(group))) => group,
+            _ => return None,
+        };
+
+        let mut tokens = group.stream().into_iter().peekable();
+        let keyword = consume_any_ident(&mut tokens)?;
+        let restriction = match keyword.to_string().as_str() {
+            "crate" => VisRestriction::Crate,
+            "super" => VisRestriction::Super,
+            "self" => VisRestriction::SelfMod,
+            "in" => return consume_path(tokens).map(VisRestriction::In),
+            _ => return None,
+        };
+
+        match tokens.peek() {
+            None => Some(restriction),
+            Some(_) => None,
+        }
+    }
+}
+
+impl EnumVariant {
+    /// Returns true if the variant doesn't store a type.
+    pub fn is_empty_variant(&self) -> bool {
+        matches!(self.fields, Fields::Unit)
+    }
+
+    /// Returns true if the variant has no fields, e.g. `A`.
+    ///
+    /// Same as [`Self::is_empty_variant()`].
+    pub fn is_unit(&self) -> bool {
+        matches!(self.fields, Fields::Unit)
+    }
+
+    /// Returns true if the variant has tuple fields, e.g. `A(i32, String)`.
+    pub fn is_tuple(&self) -> bool {
+        matches!(self.fields, Fields::Tuple(_))
+    }
+
+    /// Returns true if the variant has named fields, e.g. `A { x: i32, y: String }`.
+    pub fn is_named(&self) -> bool {
+        matches!(self.fields, Fields::Named(_))
+    }
+
+    /// Returns an iterator over the variant's field types, regardless of whether the fields are named.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use quote::quote;
+    /// let enum_type = parse_item(quote!(
+    ///     enum Hello {
+    ///         A,
+    ///         B(Foo, Bar),
+    ///         C { foo: Foo },
+    ///     }
+    /// )).unwrap();
+    /// let enum_type = enum_type.as_enum().unwrap();
+    /// let field_counts: Vec<_> = enum_type
+    ///     .variants
+    ///     .items()
+    ///     .map(|variant| variant.fields_iter().count())
+    ///     .collect();
+    /// assert_eq!(field_counts, [0, 2, 1]);
+    /// ```
+    pub fn fields_iter(&self) -> impl Iterator<Item = &TypeExpr> {
+        let types: Vec<_> = match &self.fields {
+            Fields::Unit => Vec::new(),
+            Fields::Tuple(tuple_fields) => {
+                tuple_fields.fields.items().map(|field| &field.ty).collect()
+            }
+            Fields::Named(named_fields) => {
+                named_fields.fields.items().map(|field| &field.ty).collect()
+            }
+        };
+        types.into_iter()
+    }
+
+    /// Returns Some if the variant is a wrapper around a single type.
+    /// Returns None otherwise.
+    pub fn get_single_type(&self) -> Option<&TupleField> {
+        match &self.fields {
+            Fields::Tuple(fields) if fields.fields.len() == 1 => Some(&fields.fields[0].0),
+            Fields::Tuple(_fields) => None,
+            Fields::Unit => None,
+            Fields::Named(_) => None,
+        }
+    }
+}
+
+impl EnumVariantValue {
+    /// Returns the tokens of the discriminant expression, without the `=`.
+    ///
+    /// Unlike [`EnumVariantValue::value`], this never wraps multi-token expressions in an invisible group.
+    pub fn value_tokens(&self) -> TokenStream {
+        match &self.value {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => group.stream(),
+            token => TokenStream::from(token.clone()),
+        }
+    }
+
+    /// Returns the value of the discriminant if it is an integer literal, possibly negated, such as `42`, `0x2A` or `-1`.
+    ///
+    /// Returns None for other expressions, or if the value doesn't fit into `i64`.
+    pub fn value_int(&self) -> Option<i64> {
+        let tokens: Vec<TokenTree> = self.value_tokens().into_iter().collect();
+        signed_int_value(&tokens)
+    }
+}
+
+impl Fields {
+    /// Returns the types of all fields, in order, regardless of whether the fields are named.
+    ///
+    /// Returns an empty Vec for unit fields.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use quote::quote;
+    /// let struct_type = parse_item(quote!(
+    ///     struct Hello {
+    ///         a: Foo,
+    ///         b: Vec<Bar>,
+    ///     }
+    /// )).unwrap();
+    /// let fields = &struct_type.as_struct().unwrap().fields;
+    /// let types: Vec<_> = fields.as_types().iter().map(|ty| quote!(#ty).to_string()).collect();
+    /// assert_eq!(types, ["Foo", "Vec < Bar >"]);
+    /// assert_eq!(fields.field_count(), 2);
+    /// ```
+    pub fn as_types(&self) -> Vec<TypeExpr> {
+        match self {
+            Fields::Unit => Vec::new(),
+            Fields::Tuple(tuple_fields) => tuple_fields
+                .fields
+                .items()
+                .map(|field| field.ty.clone())
+                .collect(),
+            Fields::Named(named_fields) => named_fields
+                .fields
+                .items()
+                .map(|field| field.ty.clone())
+                .collect(),
+        }
+    }
+
+    /// Returns the number of fields; 0 for unit fields.
+    pub fn field_count(&self) -> usize {
+        match self {
+            Fields::Unit => 0,
+            Fields::Tuple(tuple_fields) => tuple_fields.fields.len(),
+            Fields::Named(named_fields) => named_fields.fields.len(),
+        }
+    }
+
+    /// Returns the tokens to access each field in an expression such as `self.field`.
+    ///
+    /// Named fields yield their name. Tuple fields yield their index as an unsuffixed
+    /// integer literal, with the span of the field's type.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use quote::quote;
+    /// let struct_type = parse_item(quote!(
+    ///     struct Hello(Foo, Bar);
+    /// )).unwrap();
+    /// let members = struct_type.as_struct().unwrap().fields.members();
+    /// let members = members.map(|member| quote!(self.#member));
+    /// assert_eq!(quote!(#(#members),*).to_string(), "self . 0 , self . 1");
+    /// ```
+    pub fn members(&self) -> impl Iterator<Item = TokenTree> {
+        let members: Vec<_> = match self {
+            Fields::Unit => Vec::new(),
+            Fields::Tuple(tuple_fields) => tuple_fields
+                .fields
+                .items()
+                .enumerate()
+                .map(|(i, field)| {
+                    let mut index = Literal::usize_unsuffixed(i);
+                    index.set_span(field.ty.span());
+                    TokenTree::Literal(index)
+                })
+                .collect(),
+            Fields::Named(named_fields) => named_fields
+                .fields
+                .items()
+                .map(NamedField::as_member)
+                .collect(),
+        };
+        members.into_iter()
+    }
+}
+
+impl NamedField {
+    /// Returns the field name as a token, to access the field in an expression such as `self.field`.
+    pub fn as_member(&self) -> TokenTree {
+        TokenTree::Ident(self.name.clone())
+    }
+}
+
+impl TupleFields {
+    /// Returns identifiers `{prefix}0`, `{prefix}1`, ... to bind the fields in a pattern.
+    ///
+    /// Each identifier has the span of its field's type.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use quote::quote;
+    /// # use venial::Fields;
+    /// let struct_type = parse_item(quote!(
+    ///     struct Hello(Foo, Bar);
+    /// )).unwrap();
+    /// let Fields::Tuple(fields) = &struct_type.as_struct().unwrap().fields else { unreachable!() };
+    /// let names = fields.binding_names("field");
+    /// assert_eq!(quote!(Hello(#(#names),*)).to_string(), "Hello (field0 , field1)");
+    /// ```
+    pub fn binding_names(&self, prefix: &str) -> Vec<Ident> {
+        self.fields
+            .items()
+            .enumerate()
+            .map(|(i, field)| Ident::new(&format!("{}{}", prefix, i), field.ty.span()))
+            .collect()
+    }
+}
+
+impl FnQualifiers {
+    /// Whether exactly either `const` or `unsafe` attribute is set, and no other one
+    /// (so the tokens could be the start of a constant or impl declaration)
+    pub(crate) fn has_only_const_xor_unsafe(&self) -> bool {
+        (self.tk_const.is_some() ^ self.tk_unsafe.is_some())
+            && self.tk_default.is_none()
+            && self.tk_async.is_none()
+            && self.tk_extern.is_none()
+            && self.extern_abi.is_none()
+    }
+}
+
+impl GenericParamList {
+    /// Builder method, add a [`GenericParam`].
+    ///
+    /// Add lifetime params to the beginning of the list.
+    pub fn with_param(mut self, param: GenericParam) -> Self {
+        if param.is_lifetime() {
+            self.params.insert(0, param, None);
+        } else {
+            self.params.push(param, None);
+        }
+        self
+    }
+
+    /// Returns the params in argument form, e.g. `<'a, T, N>` for `<'a: 'static, T: Clone = u8, const N: usize>`.
+    ///
+    /// See [`InlineGenericArgs`] for details.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use quote::quote;
+    /// let struct_type = parse_item(quote!(
+    ///     struct Hello<'a: 'static, T: Clone = u8, const N: usize>(&'a [T; N]);
+    /// )).unwrap();
+    /// let params = struct_type.generic_params().unwrap();
+    ///
+    /// let inline_args = params.as_inline_args();
+    /// assert_eq!(quote!(Bar #inline_args).to_string(), "Bar < 'a , T , N , >");
+    ///
+    /// let owned_args = inline_args.to_owned_args();
+    /// assert_eq!(owned_args.args.len(), 3);
+    /// ```
+    pub fn as_inline_args(&self) -> InlineGenericArgs<'_> {
+        InlineGenericArgs(self)
+    }
+
+    /// Add `bound` to every type param, keeping existing bounds. Lifetime and const params are left unchanged.
+    ///
+    /// ```
+    /// # use venial::{parse_item, TypeExpr};
+    /// # use quote::quote;
+    /// let struct_type = parse_item(quote!(
+    ///     struct Hello<'a, T, U: Clone, const N: usize>(&'a T, U);
+    /// )).unwrap();
+    /// let mut params = struct_type.generic_params().unwrap().clone();
+    /// params.add_trait_bound(TypeExpr { tokens: quote!(MyTrait).into_iter().collect() });
+    ///
+    /// assert_eq!(
+    ///     quote!(#params).to_string(),
+    ///     quote!(<'a, T: MyTrait, U: Clone + MyTrait, const N: usize>).to_string()
+    /// );
+    /// ```
+    pub fn add_trait_bound(&mut self, bound: TypeExpr) {
+        for param in self.params.items_mut().filter(|param| param.is_ty()) {
+            match &mut param.bound {
+                Some(existing) => existing.push_bound(bound.clone()),
+                None => {
+                    param.bound = Some(GenericBound {
+                        tk_colon: Punct::new(':', Spacing::Alone),
+                        tokens: bound.tokens.clone(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+impl GenericParam {
+    /// Create new lifetime param from name.
+    ///
+    /// ```
+    /// # use venial::GenericParam;
+    /// GenericParam::lifetime("a")
+    /// # ;
+    /// ```
+    pub fn lifetime(name: &str) -> Self {
+        let lifetime_ident = Ident::new(name, Span::call_site());
+        GenericParam {
+            tk_prefix: Some(Punct::new('\'', Spacing::Joint).into()),
+            name: lifetime_ident,
+            const_ty: None,
+            bound: None,
+            default: None,
+        }
+    }
+
+    /// Create new lifetime param from name and bound.
+    ///
+    /// ```
+    /// # use venial::GenericParam;
+    /// # use quote::quote;
+    /// GenericParam::bounded_lifetime("a", quote!(b + c).into_iter().collect())
+    /// # ;
+    /// ```
+    pub fn bounded_lifetime(name: &str, bound: Vec<TokenTree>) -> Self {
+        let lifetime_ident = Ident::new(name, Span::call_site());
+        GenericParam {
+            tk_prefix: Some(Punct::new('\'', Spacing::Alone).into()),
+            name: lifetime_ident,
+            const_ty: None,
+            bound: Some(GenericBound {
+                tk_colon: Punct::new(':', Spacing::Alone),
+                tokens: bound,
+            }),
+            default: None,
+        }
+    }
+
+    /// Create new type param from name.
+    ///
+    /// ```
+    /// # use venial::GenericParam;
+    /// GenericParam::ty("T")
+    /// # ;
+    /// ```
+    pub fn ty(name: &str) -> Self {
+        let ty_ident = Ident::new(name, Span::call_site());
+        GenericParam {
+            tk_prefix: None,
+            name: ty_ident,
+            const_ty: None,
+            bound: None,
+            default: None,
+        }
+    }
+
+    /// Create new type param from name and bound.
+    ///
+    /// ```
+    /// # use venial::GenericParam;
+    /// # use quote::quote;
+    /// GenericParam::bounded_ty("T", quote!(Debug + Eq).into_iter().collect())
+    /// # ;
+    /// ```
+    pub fn bounded_ty(name: &str, bound: Vec<TokenTree>) -> Self {
+        let ty_ident = Ident::new(name, Span::call_site());
+        GenericParam {
+            tk_prefix: None,
+            name: ty_ident,
+            const_ty: None,
+            bound: Some(GenericBound {
+                tk_colon: Punct::new(':', Spacing::Alone),
+                tokens: bound,
+            }),
+            default: None,
+        }
+    }
+
+    /// Create new const param from name and type.
+    ///
+    /// ```
+    /// # use venial::GenericParam;
+    /// # use quote::quote;
+    /// GenericParam::const_param("N", quote!(i32).into_iter().collect())
+    /// # ;
+    /// ```
+    pub fn const_param(name: &str, ty: Vec<TokenTree>) -> Self {
+        let const_ident = Ident::new(name, Span::call_site());
+        GenericParam {
+            tk_prefix: Some(Ident::new("const", Span::call_site()).into()),
+            name: const_ident,
+            const_ty: Some((Punct::new(':', Spacing::Alone), TypeExpr { tokens: ty })),
+            bound: None,
+            default: None,
+        }
+    }
+
+    /// Returns whether the generic param is a lifetime, type or const param.
+    ///
+    /// ```
+    /// # use venial::{GenericParam, GenericParamKind};
+    /// assert_eq!(GenericParam::lifetime("a").kind(), GenericParamKind::Lifetime);
+    /// assert_eq!(GenericParam::ty("T").kind(), GenericParamKind::Type);
+    /// ```
+    pub fn kind(&self) -> GenericParamKind {
+        match &self.tk_prefix {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '\'' => GenericParamKind::Lifetime,
+            Some(TokenTree::Ident(ident)) if ident == "const" => GenericParamKind::Const,
+            _ => GenericParamKind::Type,
         }
     }
 
     /// Returns true if the generic param is a lifetime param.
     pub fn is_lifetime(&self) -> bool {
-        matches!(
-            &self.tk_prefix,
-            Some(TokenTree::Punct(punct)) if punct.as_char() == '\''
-        )
+        self.kind() == GenericParamKind::Lifetime
     }
 
     /// Returns true if the generic param is a type param.
     pub fn is_ty(&self) -> bool {
-        self.tk_prefix.is_none()
+        self.kind() == GenericParamKind::Type
     }
 
     /// Returns true if the generic param is a const param.
     pub fn is_const(&self) -> bool {
-        matches!(
-            &self.tk_prefix,
-            Some(TokenTree::Ident(ident)) if ident == "const"
-        )
+        self.kind() == GenericParamKind::Const
+    }
+
+    /// Returns the type of a const param, e.g. `usize` in `const N: usize`.
+    ///
+    /// Returns None if the param is not a const param.
+    pub fn const_ty(&self) -> Option<&TypeExpr> {
+        match &self.const_ty {
+            Some((_tk_colon, ty)) if self.is_const() => Some(ty),
+            _ => None,
+        }
+    }
+}
+
+impl GenericBound {
+    /// Returns the individual bounds, split on `+` signs.
+    ///
+    /// Nested `<>` and `()` are taken into account, so `Fn(A) -> B + Send` yields
+    /// two bounds: `Fn(A) -> B` and `Send`.
+    ///
+    /// Rust accepts a trailing `+`, as in `T: Send +`. It is not returned as a bound, but
+    /// [`Punctuated::has_trailing_punct()`] is true for the result. Empty bounds between two `+` are skipped.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use quote::quote;
+    /// let struct_type = parse_item(quote!(
+    ///     struct Hello<T: Clone + Send>(T);
+    /// )).unwrap();
+    /// let param = &struct_type.generic_params().unwrap().params[0].0;
+    /// let bounds = param.bound.as_ref().unwrap().bounds();
+    /// assert_eq!(bounds.len(), 2);
+    /// ```
+    pub fn bounds(&self) -> Punctuated<TypeExpr> {
+        parse_bound_list(&self.tokens)
+    }
+
+    /// Append `bound` after the existing bounds, inserting a `+` unless the bound list is empty or already ends with one.
+    fn push_bound(&mut self, bound: TypeExpr) {
+        let ends_with_plus = matches!(
+            self.tokens.last(),
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '+'
+        );
+        if !self.tokens.is_empty() && !ends_with_plus {
+            self.tokens.push(Punct::new('+', Spacing::Alone).into());
+        }
+        self.tokens.extend(bound.tokens);
+    }
+
+    /// Returns the lifetime bounds, e.g. `'a` and `'static` in `T: 'a + 'static + Clone`.
+    pub fn lifetime_bounds(&self) -> Vec<Lifetime> {
+        self.bounds()
+            .items()
+            .filter_map(|bound| bound_as_lifetime(&bound.tokens))
+            .collect()
+    }
+
+    /// Returns the trait bounds, e.g. `Clone` in `T: 'a + Clone`.
+    ///
+    /// Relaxed bounds such as `?Sized` are not included, see [`Self::maybe_bounds()`].
+    /// Negative bounds such as `!Send` are not included either, see [`Self::negative_bounds()`].
+    pub fn trait_bounds(&self) -> Vec<TypeExpr> {
+        self.bounds()
+            .inner
+            .into_iter()
+            .map(|(bound, _punct)| bound)
+            .filter(|bound| bound_as_lifetime(&bound.tokens).is_none())
+            .filter(|bound| strip_bound_modifier(&bound.tokens, '?').is_none())
+            .filter(|bound| strip_bound_modifier(&bound.tokens, '!').is_none())
+            .collect()
+    }
+
+    /// Returns the relaxed bounds, without their leading `?`.
+    ///
+    /// For instance, `T: ?Sized + Send` has the maybe-bound `Sized`.
+    pub fn maybe_bounds(&self) -> Vec<TypeExpr> {
+        self.bounds()
+            .items()
+            .filter_map(|bound| strip_bound_modifier(&bound.tokens, '?'))
+            .collect()
+    }
+
+    /// Returns the negative bounds, without their leading `!`.
+    ///
+    /// For instance, `T: !Send + Clone` has the negative bound `Send`. Negative bounds are unstable Rust;
+    /// the `!` is kept in [`Self::tokens`] regardless.
+    pub fn negative_bounds(&self) -> Vec<TypeExpr> {
+        self.bounds()
+            .items()
+            .filter_map(|bound| strip_bound_modifier(&bound.tokens, '!'))
+            .collect()
+    }
+}
+
+/// If the bound starts with `modifier` (e.g. `?` in `?Sized`), returns the rest of the bound.
+fn strip_bound_modifier(tokens: &[TokenTree], modifier: char) -> Option<TypeExpr> {
+    match tokens {
+        [TokenTree::Punct(punct), rest @ ..] if punct.as_char() == modifier => Some(TypeExpr {
+            tokens: rest.to_vec(),
+        }),
+        _ => None,
+    }
+}
+
+fn bound_as_lifetime(tokens: &[TokenTree]) -> Option<Lifetime> {
+    match tokens {
+        [TokenTree::Punct(tk_apostrophe), TokenTree::Ident(name)]
+            if tk_apostrophe.as_char() == '\'' =>
+        {
+            Some(Lifetime {
+                tk_apostrophe: tk_apostrophe.clone(),
+                name: name.clone(),
+            })
+        }
+        _ => None,
     }
 }
 
@@ -707,6 +1662,7 @@ impl InlineGenericArgs<'_> {
                     .iter()
                     .map(|(param, punctuated_punct)| {
                         let name = param.name.clone();
+                        // Lifetimes keep their `'`, while type and const params become bare idents
                         let arg = match &param.tk_prefix {
                             Some(TokenTree::Punct(punct)) if punct.as_char() == '\'' => {
                                 GenericArg::Lifetime {
@@ -741,6 +1697,82 @@ impl InlineGenericArgs<'_> {
     }
 }
 
+impl GenericArgList {
+    /// Returns the number of arguments.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns true if there are no arguments, e.g. `Foo<>`.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Returns an iterator over the arguments, without their separators.
+    pub fn iter(&self) -> impl Iterator<Item = &GenericArg> {
+        self.args.items()
+    }
+
+    /// Returns an iterator over the type (or const) arguments, skipping lifetimes, bindings and associated bounds.
+    ///
+    /// ```
+    /// # use venial::TypeExpr;
+    /// # use quote::quote;
+    /// let ty = TypeExpr { tokens: quote!(Foo<'a, T, Item = u8, 32>).into_iter().collect() };
+    /// let path = ty.as_path().unwrap();
+    /// let args = path.segments[0].generic_args.as_ref().unwrap();
+    ///
+    /// let types: Vec<String> = args.types().map(|ty| quote!(#ty).to_string()).collect();
+    /// assert_eq!(types, ["T", "32"]);
+    /// assert_eq!(args.len(), 4);
+    /// ```
+    pub fn types(&self) -> impl Iterator<Item = &TypeExpr> {
+        self.iter().filter_map(|arg| match arg {
+            GenericArg::TypeOrConst { expr } => Some(expr),
+            _ => None,
+        })
+    }
+}
+
+impl GenericArg {
+    /// Returns the tokens of a const argument, such as `3`, `-1`, `true` or `{ N + 1 }`.
+    ///
+    /// Braced expressions are returned verbatim, including the braces.
+    /// Returns None for other arguments; in particular, a bare identifier such as `N` may be
+    /// a type or a constant, so it is not recognized.
+    ///
+    /// ```
+    /// # use venial::parse_item;
+    /// # use quote::quote;
+    /// let struct_type = parse_item(quote!(
+    ///     struct Hello(Foo<{ N + 1 }, 3, T>);
+    /// )).unwrap();
+    /// let ty = &struct_type.as_struct().unwrap().fields.as_types()[0];
+    /// let path = ty.as_path().unwrap();
+    /// let args = &path.segments[0].generic_args.as_ref().unwrap().args;
+    /// let const_args: Vec<_> = args
+    ///     .items()
+    ///     .map(|arg| arg.as_const_expr().map(|expr| expr.to_string()))
+    ///     .collect();
+    /// assert_eq!(const_args, [Some("{ N + 1 }".to_string()), Some("3".to_string()), None]);
+    /// ```
+    pub fn as_const_expr(&self) -> Option<TokenStream> {
+        let expr = match self {
+            GenericArg::TypeOrConst { expr } => expr,
+            _ => return None,
+        };
+
+        match expr.tokens.as_slice() {
+            [TokenTree::Group(group)] if group.delimiter() == Delimiter::Brace => {}
+            [TokenTree::Literal(_)] => {}
+            [TokenTree::Ident(ident)] if ident == "true" || ident == "false" => {}
+            [TokenTree::Punct(minus), TokenTree::Literal(_)] if minus.as_char() == '-' => {}
+            _ => return None,
+        }
+        Some(expr.tokens.iter().cloned().collect())
+    }
+}
+
 impl WhereClause {
     /// Create where-clause with a single predicate.
     pub fn from_predicate(item: WhereClausePredicate) -> Self {
@@ -752,6 +1784,50 @@ impl WhereClause {
         self.items.push(item, None);
         self
     }
+
+    /// Returns true if the where-clause has no predicates, e.g. a bare `where`.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Add a predicate `ty: bound` to the where-clause.
+    ///
+    /// ```
+    /// # use venial::{TypeExpr, WhereClause};
+    /// # use quote::quote;
+    /// let mut where_clause = WhereClause::default();
+    /// where_clause.push_predicate(TypeExpr { tokens: quote!(T).into_iter().collect() }, quote!(Clone + Send));
+    /// assert_eq!(quote!(#where_clause).to_string(), "where T : Clone + Send");
+    /// ```
+    pub fn push_predicate(&mut self, ty: TypeExpr, bound: TokenStream) {
+        let predicate = WhereClausePredicate {
+            left_side: ty.tokens,
+            bound: GenericBound {
+                tk_colon: Punct::new(':', Spacing::Alone),
+                tokens: bound.into_iter().collect(),
+            },
+        };
+        self.items.push(predicate, None);
+    }
+
+    /// Append the predicates of `other` to this where-clause.
+    ///
+    /// Commas are inserted as needed, and the result has a trailing comma only if `other` had one.
+    /// If `other` has no predicates, this where-clause is left unchanged, including its trailing comma.
+    ///
+    /// ```
+    /// # use venial::{WhereClause, WhereClausePredicate};
+    /// # use quote::quote;
+    /// let mut where_clause = WhereClause::from_predicate(WhereClausePredicate::parse(quote!(T: Clone)));
+    /// let other = WhereClause::from_predicate(WhereClausePredicate::parse(quote!(U: Send)));
+    /// where_clause.merge(other);
+    /// assert_eq!(quote!(#where_clause).to_string(), "where T : Clone , U : Send");
+    /// ```
+    pub fn merge(&mut self, other: WhereClause) {
+        for (predicate, comma) in other.items.into_pairs() {
+            self.items.push(predicate, comma);
+        }
+    }
 }
 
 impl WhereClausePredicate {
@@ -793,20 +1869,470 @@ impl WhereClausePredicate {
             },
         }
     }
+
+    /// Returns the constrained type on the left side of the `:`, e.g. `T` in `T: Clone`.
+    ///
+    /// This allows reusing [`TypeExpr`] helpers such as [`TypeExpr::as_path()`].
+    pub fn left_ty(&self) -> TypeExpr {
+        TypeExpr {
+            tokens: self.left_side.clone(),
+        }
+    }
+
+    /// If this is a lifetime outlives predicate such as `'a: 'b + 'c`, returns the constrained
+    /// lifetime and its bounds.
+    ///
+    /// Returns `None` if the left side is not a lifetime, e.g. for `T: Clone`.
+    pub fn as_lifetime_outlives(&self) -> Option<(Lifetime, Vec<Lifetime>)> {
+        let lifetime = bound_as_lifetime(&self.left_side)?;
+        Some((lifetime, self.bound.lifetime_bounds()))
+    }
+
+    /// Returns the lifetimes introduced by a higher-ranked `for<...>` prefix.
+    ///
+    /// For instance, `for<'a, 'b> F: Fn(&'a u8, &'b u8)` yields `'a` and `'b`.
+    /// Returns an empty list if there is no such prefix.
+    pub fn for_lifetimes(&self) -> Vec<Lifetime> {
+        let mut tokens = tokens_from_slice(&self.left_side);
+        if consume_ident(&mut tokens, "for").is_none() || consume_punct(&mut tokens, '<').is_none()
+        {
+            return vec![];
+        }
+
+        let mut lifetimes = vec![];
+        while let Some(lifetime) = consume_lifetime(&mut tokens) {
+            lifetimes.push(lifetime);
+            if consume_comma(&mut tokens).is_none() {
+                break;
+            }
+        }
+        lifetimes
+    }
+}
+
+impl ReferenceType {
+    /// Returns true for `&mut T` references.
+    pub fn is_mut(&self) -> bool {
+        self.tk_mut.is_some()
+    }
+}
+
+impl PointerType {
+    /// Returns true for `*mut T` pointers.
+    pub fn is_mut(&self) -> bool {
+        self.tk_const_or_mut == "mut"
+    }
 }
 
 impl TypeExpr {
     /// Tries to parse this type as a [`Path`] such as `path::to::Type<'a, other::Type>`.
     ///
     /// If it does not match a path, `None` is returned.
+    ///
+    /// For absolute paths, the leading `::` is stored in the first segment's `tk_separator_colons`.
+    ///
+    /// ```
+    /// # use venial::TypeExpr;
+    /// # use quote::quote;
+    /// let ty = TypeExpr { tokens: quote!(::std::collections::HashMap<K, V>).into_iter().collect() };
+    /// let path = ty.as_path().unwrap();
+    ///
+    /// let first = &path.segments[0];
+    /// assert!(first.tk_separator_colons.is_some());
+    /// assert_eq!(first.ident.to_string(), "std");
+    ///
+    /// let last = path.segments.last().unwrap();
+    /// assert_eq!(last.ident.to_string(), "HashMap");
+    /// assert_eq!(last.generic_args.as_ref().unwrap().args.len(), 2);
+    /// ```
     pub fn as_path(&self) -> Option<Path> {
-        let tokens = if let Some(path) = self.unwrap_invisible_group() {
-            tokens_from_slice(&path)
-        } else {
-            tokens_from_slice(&self.tokens)
+        consume_path(tokens_from_slice(&self.unwrapped_tokens()))
+    }
+
+    /// Tries to parse this type as a [`QualifiedPath`] such as `<T as Trait>::Assoc` or `<T>::Assoc`.
+    ///
+    /// If it is not a qualified path, `None` is returned. For unqualified paths, see [`Self::as_path()`].
+    ///
+    /// ```
+    /// # use venial::TypeExpr;
+    /// # use quote::quote;
+    /// let ty = TypeExpr { tokens: quote!(<Vec<T> as IntoIterator>::Item).into_iter().collect() };
+    /// let qualified_path = ty.as_qualified_path().unwrap();
+    ///
+    /// let self_ty = qualified_path.self_ty;
+    /// assert_eq!(quote!(#self_ty).to_string(), "Vec < T >");
+    /// let trait_path = qualified_path.trait_path.unwrap();
+    /// assert_eq!(quote!(#trait_path).to_string(), "IntoIterator");
+    /// assert_eq!(qualified_path.path.segments[0].ident.to_string(), "Item");
+    /// ```
+    pub fn as_qualified_path(&self) -> Option<QualifiedPath> {
+        let mut tokens = tokens_from_slice(&self.unwrapped_tokens());
+
+        let tk_l_bracket = consume_punct(&mut tokens, '<')?;
+        // Stops at a top-level `as` or at the unmatched closing `>`
+        let self_tokens = consume_stuff_until(
+            &mut tokens,
+            |token| matches!(token, TokenTree::Ident(ident) if ident == "as"),
+            false,
+        );
+        if self_tokens.is_empty() {
+            return None;
+        }
+
+        let tk_as = consume_ident(&mut tokens, "as");
+        let trait_path = match tk_as {
+            Some(_) => {
+                let trait_tokens = consume_stuff_until(&mut tokens, |_| false, false);
+                Some(consume_path(tokens_from_slice(&trait_tokens))?)
+            }
+            None => None,
+        };
+        let tk_r_bracket = consume_punct(&mut tokens, '>')?;
+
+        let path = consume_path(tokens)?;
+        // The path must be separated from `>` by `::`
+        path.segments[0].tk_separator_colons.as_ref()?;
+
+        Some(QualifiedPath {
+            tk_l_bracket,
+            self_ty: TypeExpr {
+                tokens: self_tokens,
+            },
+            tk_as,
+            trait_path,
+            tk_r_bracket,
+            path,
+        })
+    }
+
+    /// If this type is a path whose last segment is `name` with a single generic type argument, returns that argument.
+    ///
+    /// Only the last segment is compared, so both `Option<T>` and `core::option::Option<T>` match `"Option"`.
+    ///
+    /// ```
+    /// # use venial::TypeExpr;
+    /// # use quote::quote;
+    /// let ty = TypeExpr { tokens: quote!(std::option::Option<i32>).into_iter().collect() };
+    /// let inner = ty.as_wrapper("Option").unwrap();
+    /// assert_eq!(quote!(#inner).to_string(), "i32");
+    /// assert!(ty.as_wrapper("Vec").is_none());
+    /// ```
+    pub fn as_wrapper(&self, name: &str) -> Option<TypeExpr> {
+        let path = self.as_path()?;
+        let last_segment = path.segments.last()?;
+        if last_segment.ident != name {
+            return None;
+        }
+
+        let generic_args = last_segment.generic_args.as_ref()?;
+        match &generic_args.args[..] {
+            [(GenericArg::TypeOrConst { expr }, _punct)] => Some(expr.clone()),
+            _ => None,
+        }
+    }
+
+    /// Tries to parse this type as a trait object such as `dyn Trait + Send`, returning its bounds.
+    ///
+    /// ```
+    /// # use venial::TypeExpr;
+    /// # use quote::quote;
+    /// let ty = TypeExpr { tokens: quote!(dyn Fn(u8) -> u16 + Send).into_iter().collect() };
+    /// let bounds = ty.as_dyn().unwrap();
+    /// assert_eq!(bounds.len(), 2);
+    /// assert!(ty.as_impl().is_none());
+    /// ```
+    pub fn as_dyn(&self) -> Option<Punctuated<TypeExpr>> {
+        self.bounds_after_keyword("dyn")
+    }
+
+    /// Tries to parse this type as an `impl Trait + Send` type, returning its bounds.
+    pub fn as_impl(&self) -> Option<Punctuated<TypeExpr>> {
+        self.bounds_after_keyword("impl")
+    }
+
+    fn bounds_after_keyword(&self, keyword: &str) -> Option<Punctuated<TypeExpr>> {
+        match self.unwrapped_tokens().as_slice() {
+            [TokenTree::Ident(ident), bounds @ ..] if ident == keyword => {
+                Some(parse_bound_list(bounds))
+            }
+            _ => None,
+        }
+    }
+
+    /// Tries to parse this type as a [`ReferenceType`] such as `&'a mut T`.
+    ///
+    /// If it is not a reference type, `None` is returned.
+    ///
+    /// ```
+    /// # use venial::TypeExpr;
+    /// # use quote::quote;
+    /// let ty = TypeExpr { tokens: quote!(&'a mut Vec<i32>).into_iter().collect() };
+    /// let reference = ty.as_reference().unwrap();
+    /// assert_eq!(reference.lifetime.as_ref().unwrap().name.to_string(), "a");
+    /// assert!(reference.is_mut());
+    ///
+    /// let inner = reference.inner;
+    /// assert_eq!(quote!(#inner).to_string(), quote!(Vec<i32>).to_string());
+    /// ```
+    pub fn as_reference(&self) -> Option<ReferenceType> {
+        let mut tokens = tokens_from_slice(&self.unwrapped_tokens());
+
+        let tk_ref = consume_punct(&mut tokens, '&')?;
+        let lifetime = match tokens.peek() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '\'' => {
+                let tk_apostrophe = consume_punct(&mut tokens, '\'')?;
+                let name = consume_any_ident(&mut tokens)?;
+                Some(Lifetime {
+                    tk_apostrophe,
+                    name,
+                })
+            }
+            _ => None,
+        };
+        let tk_mut = consume_ident(&mut tokens, "mut");
+
+        let inner: Vec<TokenTree> = tokens.collect();
+        if inner.is_empty() {
+            return None;
+        }
+
+        Some(ReferenceType {
+            tk_ref,
+            lifetime,
+            tk_mut,
+            inner: TypeExpr { tokens: inner },
+        })
+    }
+
+    /// Returns true if this is the unit type `()`.
+    pub fn is_unit(&self) -> bool {
+        match self.unwrapped_tokens().as_slice() {
+            [TokenTree::Group(group)] => {
+                group.delimiter() == Delimiter::Parenthesis && group.stream().is_empty()
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if this is exactly the `Self` type.
+    ///
+    /// Associated types such as `Self::Item` return false; use [`Self::as_path()`] and check the first segment instead.
+    ///
+    /// ```
+    /// # use venial::TypeExpr;
+    /// # use quote::quote;
+    /// let ty = TypeExpr { tokens: quote!(Self).into_iter().collect() };
+    /// assert!(ty.is_self_ty());
+    ///
+    /// let ty = TypeExpr { tokens: quote!(Self::Item).into_iter().collect() };
+    /// assert!(!ty.is_self_ty());
+    /// assert_eq!(ty.as_path().unwrap().segments[0].ident.to_string(), "Self");
+    /// ```
+    pub fn is_self_ty(&self) -> bool {
+        matches!(
+            self.unwrapped_tokens().as_slice(),
+            [TokenTree::Ident(ident)] if ident == "Self"
+        )
+    }
+
+    /// Returns true if this is the never type `!`.
+    pub fn is_never(&self) -> bool {
+        matches!(
+            self.unwrapped_tokens().as_slice(),
+            [TokenTree::Punct(punct)] if punct.as_char() == '!'
+        )
+    }
+
+    /// Tries to parse this type as a raw [`PointerType`] such as `*const T` or `*mut T`.
+    ///
+    /// If it is not a raw pointer type, `None` is returned.
+    pub fn as_pointer(&self) -> Option<PointerType> {
+        match self.unwrapped_tokens().as_slice() {
+            [TokenTree::Punct(tk_star), TokenTree::Ident(tk_const_or_mut), inner @ ..]
+                if tk_star.as_char() == '*'
+                    && (tk_const_or_mut == "const" || tk_const_or_mut == "mut")
+                    && !inner.is_empty() =>
+            {
+                Some(PointerType {
+                    tk_star: tk_star.clone(),
+                    tk_const_or_mut: tk_const_or_mut.clone(),
+                    inner: TypeExpr {
+                        tokens: inner.to_vec(),
+                    },
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Tries to parse this type as a [`FnPointerType`] such as `unsafe extern "C" fn(i32, ...) -> bool`.
+    ///
+    /// If it is not a function pointer type, `None` is returned.
+    ///
+    /// ```
+    /// # use venial::TypeExpr;
+    /// # use quote::quote;
+    /// let ty = TypeExpr { tokens: quote!(for<'a> fn(&'a u8, len: usize) -> bool).into_iter().collect() };
+    /// let fn_pointer = ty.as_fn_pointer().unwrap();
+    /// assert_eq!(fn_pointer.params.len(), 2);
+    /// assert_eq!(fn_pointer.params[1].0.name.as_ref().unwrap().to_string(), "len");
+    ///
+    /// let return_ty = fn_pointer.return_ty.unwrap();
+    /// assert_eq!(quote!(#return_ty).to_string(), "bool");
+    /// ```
+    pub fn as_fn_pointer(&self) -> Option<FnPointerType> {
+        let mut tokens = tokens_from_slice(&self.unwrapped_tokens());
+
+        let tk_for = consume_ident(&mut tokens, "for");
+        let for_lifetimes = match tk_for {
+            Some(_) => Some(consume_generic_params(&mut tokens).ok()??),
+            None => None,
+        };
+        let tk_unsafe = consume_ident(&mut tokens, "unsafe");
+        let tk_extern = consume_ident(&mut tokens, "extern");
+        let extern_abi = match (&tk_extern, tokens.peek()) {
+            (Some(_), Some(TokenTree::Literal(literal))) => {
+                let literal = literal.clone();
+                tokens.next();
+                Some(literal)
+            }
+            _ => None,
+        };
+        let tk_fn = consume_ident(&mut tokens, "fn")?;
+
+        let params_group = match tokens.next() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => group,
+            _ => return None,
+        };
+
+        let tk_return_arrow = match consume_punct(&mut tokens, '-') {
+            Some(dash) => Some([dash, consume_punct(&mut tokens, '>')?]),
+            None => None,
+        };
+        let return_tokens: Vec<TokenTree> = tokens.collect();
+        let return_ty = match (&tk_return_arrow, return_tokens.is_empty()) {
+            (Some(_), false) => Some(TypeExpr {
+                tokens: return_tokens,
+            }),
+            (None, true) => None,
+            // Missing return type, or trailing tokens without `->`
+            _ => return None,
+        };
+
+        let (params, tk_variadic) = parse_fn_pointer_params(params_group.stream())?;
+
+        Some(FnPointerType {
+            tk_for,
+            for_lifetimes,
+            tk_unsafe,
+            tk_extern,
+            extern_abi,
+            tk_fn,
+            tk_params_parens: GroupSpan::new(&params_group),
+            params,
+            tk_variadic,
+            tk_return_arrow,
+            return_ty,
+        })
+    }
+
+    /// Tries to parse this type as a tuple type such as `(A, B, C)`, returning the element types.
+    ///
+    /// The unit type `()` yields an empty list, while a parenthesized type such as `(A)` is not a tuple
+    /// and returns `None`.
+    pub fn as_tuple(&self) -> Option<Punctuated<TypeExpr>> {
+        let group = match self.unwrapped_tokens().as_slice() {
+            [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+                group.clone()
+            }
+            _ => return None,
+        };
+
+        let mut tokens = group.stream().into_iter().peekable();
+        let mut types = Punctuated::new();
+        let mut has_trailing_comma = false;
+        loop {
+            if tokens.peek().is_none() {
+                break;
+            }
+
+            let ty_tokens = consume_stuff_until(
+                &mut tokens,
+                |token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','),
+                false,
+            );
+            let comma = consume_comma(&mut tokens);
+            has_trailing_comma = comma.is_some();
+            types.push(TypeExpr { tokens: ty_tokens }, comma);
+        }
+
+        if types.len() == 1 && !has_trailing_comma {
+            return None;
+        }
+        Some(types)
+    }
+
+    /// Tries to parse this type as a slice type such as `[T]`, returning the element type.
+    ///
+    /// Arrays such as `[T; N]` return `None`, see [`Self::as_array()`].
+    pub fn as_slice(&self) -> Option<TypeExpr> {
+        match self.split_bracketed()? {
+            (element_ty, None) => Some(element_ty),
+            (_, Some(_)) => None,
+        }
+    }
+
+    /// Tries to parse this type as an array type such as `[T; N]`, returning the element type and length.
+    ///
+    /// Since the length can be any const expression, it is returned as an unparsed [`ValueExpr`].
+    ///
+    /// ```
+    /// # use venial::TypeExpr;
+    /// # use quote::quote;
+    /// let ty = TypeExpr { tokens: quote!([u8; 4 * SIZE]).into_iter().collect() };
+    /// let (element_ty, len) = ty.as_array().unwrap();
+    /// assert_eq!(quote!(#element_ty).to_string(), "u8");
+    /// assert_eq!(quote!(#len).to_string(), "4 * SIZE");
+    /// ```
+    pub fn as_array(&self) -> Option<(TypeExpr, ValueExpr)> {
+        match self.split_bracketed()? {
+            (element_ty, Some(len)) => Some((element_ty, len)),
+            (_, None) => None,
+        }
+    }
+
+    /// Splits `[T; N]` into `T` and `N`, or `[T]` into `T` and `None`.
+    fn split_bracketed(&self) -> Option<(TypeExpr, Option<ValueExpr>)> {
+        let group = match self.unwrapped_tokens().as_slice() {
+            [TokenTree::Group(group)] if group.delimiter() == Delimiter::Bracket => group.clone(),
+            _ => return None,
+        };
+
+        let mut tokens = group.stream().into_iter().peekable();
+        let element_tokens = consume_stuff_until(
+            &mut tokens,
+            |token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ';'),
+            false,
+        );
+        let element_ty = TypeExpr {
+            tokens: element_tokens,
         };
 
-        consume_path(tokens)
+        match consume_punct(&mut tokens, ';') {
+            Some(_tk_semicolon) => Some((
+                element_ty,
+                Some(ValueExpr {
+                    tokens: tokens.collect(),
+                }),
+            )),
+            None => Some((element_ty, None)),
+        }
+    }
+
+    /// Returns the tokens of this type, with a top-level invisible group (see below) unwrapped.
+    fn unwrapped_tokens(&self) -> Vec<TokenTree> {
+        self.unwrap_invisible_group()
+            .unwrap_or_else(|| self.tokens.clone())
     }
 
     /// If the type has a top-level `Group` token without separator, extract the contents. Otherwise return `None`.
@@ -820,10 +2346,114 @@ impl TypeExpr {
     }
 }
 
+/// Splits the parameters of a function pointer type into named or unnamed parameters, and the variadic `...`.
+fn parse_fn_pointer_params(
+    tokens: TokenStream,
+) -> Option<(Punctuated<FnPointerParam>, Option<[Punct; 3]>)> {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut params = Punctuated::new();
+    let mut tk_variadic = None;
+    loop {
+        if tokens.peek().is_none() {
+            break;
+        }
+
+        let param_tokens = consume_stuff_until(
+            &mut tokens,
+            |token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','),
+            false,
+        );
+        let comma = consume_comma(&mut tokens);
+
+        match param_tokens.as_slice() {
+            [TokenTree::Punct(dot1), TokenTree::Punct(dot2), TokenTree::Punct(dot3)]
+                if [dot1, dot2, dot3].iter().all(|dot| dot.as_char() == '.') =>
+            {
+                // `...` must be the last parameter
+                if tokens.peek().is_some() {
+                    return None;
+                }
+                tk_variadic = Some([dot1.clone(), dot2.clone(), dot3.clone()]);
+            }
+            // `name: Type`; a joint `:` would be the start of a `::` path instead
+            [TokenTree::Ident(name), TokenTree::Punct(colon), ty @ ..]
+                if colon.as_char() == ':' && colon.spacing() == Spacing::Alone =>
+            {
+                let param = FnPointerParam {
+                    name: Some(name.clone()),
+                    tk_colon: Some(colon.clone()),
+                    ty: TypeExpr {
+                        tokens: ty.to_vec(),
+                    },
+                };
+                params.push(param, comma);
+            }
+            _ => {
+                let param = FnPointerParam {
+                    name: None,
+                    tk_colon: None,
+                    ty: TypeExpr {
+                        tokens: param_tokens,
+                    },
+                };
+                params.push(param, comma);
+            }
+        }
+    }
+
+    Some((params, tk_variadic))
+}
+
+macro_rules! implement_attribute_helpers {
+    ($Kind:ident) => {
+        impl $Kind {
+            /// Returns true if one of the attributes has the given path, see [`Attribute::path_is()`].
+            pub fn has_attribute(&self, path: &str) -> bool {
+                self.attributes
+                    .iter()
+                    .any(|attribute| attribute.path_is(path))
+            }
+
+            /// Returns the attributes, except the ones with the given path, see [`Attribute::path_is()`].
+            ///
+            /// This is useful to re-emit attributes, after handling a macro-specific attribute.
+            pub fn without_attribute(&self, path: &str) -> Vec<Attribute> {
+                self.attributes
+                    .iter()
+                    .filter(|attribute| !attribute.path_is(path))
+                    .cloned()
+                    .collect()
+            }
+        }
+    };
+}
+
+implement_attribute_helpers!(Constant);
+implement_attribute_helpers!(Enum);
+implement_attribute_helpers!(EnumVariant);
+implement_attribute_helpers!(ExternBlock);
+implement_attribute_helpers!(ExternCrate);
+implement_attribute_helpers!(FnReceiverParam);
+implement_attribute_helpers!(FnTypedParam);
+implement_attribute_helpers!(Function);
+implement_attribute_helpers!(Impl);
+implement_attribute_helpers!(Macro);
+implement_attribute_helpers!(Module);
+implement_attribute_helpers!(NamedField);
+implement_attribute_helpers!(Struct);
+implement_attribute_helpers!(Trait);
+implement_attribute_helpers!(TupleField);
+implement_attribute_helpers!(TypeAlias);
+implement_attribute_helpers!(Union);
+implement_attribute_helpers!(UnknownItem);
+implement_attribute_helpers!(UseDeclaration);
+
 macro_rules! implement_span {
     ($Kind:ident) => {
         impl $Kind {
             /// Returns span of this item.
+            ///
+            /// On stable compilers, this is typically only the span of the first token; see [crate-level docs](crate#spans).
             pub fn span(&self) -> Span {
                 self.__span()
             }
@@ -834,10 +2464,19 @@ macro_rules! implement_span {
 implement_span!(Attribute);
 implement_span!(AttributeValue);
 implement_span!(Item);
+implement_span!(Constant);
 implement_span!(Enum);
 implement_span!(EnumVariant);
 implement_span!(EnumVariantValue);
+implement_span!(ExternBlock);
+implement_span!(ExternCrate);
 implement_span!(Function);
+implement_span!(Impl);
+implement_span!(Macro);
+implement_span!(Module);
+implement_span!(Trait);
+implement_span!(TypeAlias);
+implement_span!(UseDeclaration);
 
 implement_span!(GenericBound);
 implement_span!(GenericParam);
@@ -868,4 +2507,24 @@ impl GroupSpan {
             delimiter: group.delimiter(),
         }
     }
+
+    /// Create a [`Group`] containing `stream`, with the delimiter and span of the original group.
+    ///
+    /// ```
+    /// # use venial::{parse_item, Fields};
+    /// # use quote::quote;
+    /// let struct_type = parse_item(quote!(
+    ///     struct Hello { a: Foo, b: Bar }
+    /// )).unwrap();
+    /// let Fields::Named(fields) = &struct_type.as_struct().unwrap().fields else { unreachable!() };
+    ///
+    /// let first_field = &fields.fields[0].0;
+    /// let group = fields.tk_braces.into_group(quote!(#first_field));
+    /// assert_eq!(group.to_string(), "{ a : Foo }");
+    /// ```
+    pub fn into_group(self, stream: TokenStream) -> Group {
+        let mut group = Group::new(self.delimiter, stream);
+        group.set_span(self.span);
+        group
+    }
 }